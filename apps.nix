@@ -40,6 +40,19 @@
       G2=$(sha256sum <(gron ${large-file-json}))
       A2=$(sha256sum <(argon ${large-file-json}))
       diff <(echo "$G2") <(echo "$A2")
+
+      for f in ${./testcases/gron-compat}/*.json; do
+        printf "\nComparing --gron-compat on %s.." "$(basename "$f")"
+        diff <(gron "$f") <(argon --gron-compat "$f")
+      done
+    '';
+  };
+  gron-compat-cases = {
+    runtimeInputs = [ pkgs.bash pkgs.coreutils pkgs.gron ];
+    text = ''
+      for f in testcases/gron-compat/*.json; do
+        gron --monochrome "$f" > "''${f%.json}.js"
+      done
     '';
   };
   flamegraph = {
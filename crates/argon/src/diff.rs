@@ -0,0 +1,260 @@
+//! Structural diff of two json documents, written as the gron lines that were
+//! removed or added.
+
+use crate::gron::{process_recursively, Error, Gron, Locals};
+use patched_simd_json::{value::borrowed::Value, StaticNode};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::BTreeMap,
+    io::{self, BufWriter, Write},
+};
+
+const ANSI_REMOVED: &str = "\x1B[31m";
+const ANSI_ADDED: &str = "\x1B[32m";
+const ANSI_RESET: &str = "\x1B[0m";
+
+/// Compares two json documents, writing the gron lines only in the old one
+/// prefixed by `-` and those only in the new one prefixed by `+`. Objects and
+/// arrays present in both are compared by key and index.
+///
+/// ```
+/// let mut old = br#"{"a":1,"b":[true]}"#.to_vec();
+/// let mut new = br#"{"a":2,"b":[true,false]}"#.to_vec();
+/// let mut output = Vec::new();
+/// argon::Diff::new()
+///     .to_writer(&mut old, &mut new, &mut output)
+///     .unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "-json.a = 1;\n+json.a = 2;\n+json.b[1] = false;\n",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Diff {
+    color: bool,
+    bracket_keys: bool,
+    ignore_array_order: bool,
+    lossless_numbers: bool,
+}
+impl Diff {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Color removed lines red and added lines green.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Like [`Gron::bracket_keys`].
+    pub fn bracket_keys(mut self, bracket_keys: bool) -> Self {
+        self.bracket_keys = bracket_keys;
+        self
+    }
+
+    /// Compare arrays as multisets, so that elements that merely moved are
+    /// not reported. Elements without an equal counterpart are reported at
+    /// their index on their own side.
+    pub fn ignore_array_order(mut self, ignore_array_order: bool) -> Self {
+        self.ignore_array_order = ignore_array_order;
+        self
+    }
+
+    /// Like [`Gron::lossless_numbers`], so that `1.0` and `1` differ.
+    pub fn lossless_numbers(mut self, lossless_numbers: bool) -> Self {
+        self.lossless_numbers = lossless_numbers;
+        self
+    }
+
+    /// Parses and compares two json documents. The parser uses them as
+    /// scratch space.
+    pub fn to_writer(
+        &self,
+        old: &mut [u8],
+        new: &mut [u8],
+        output: impl io::Write,
+    ) -> Result<(), Error> {
+        let (mut old_quoted, mut new_quoted) = (Vec::new(), Vec::new());
+        let old = self
+            .gron()
+            .parse(old, &mut old_quoted)
+            .map_err(Error::Json)?;
+        let new = self
+            .gron()
+            .parse(new, &mut new_quoted)
+            .map_err(Error::Json)?;
+        self.values_to_writer(&old, &new, output)
+    }
+
+    /// Compares two already parsed documents. Strings are expected to be
+    /// escaped the way the patched simd-json leaves them.
+    pub fn values_to_writer(
+        &self,
+        old: &Value<'_>,
+        new: &Value<'_>,
+        output: impl io::Write,
+    ) -> Result<(), Error> {
+        let mut locals = Locals::new(self.gron(), Vec::new());
+        let mut output = BufWriter::new(output);
+        self.values(old, new, &mut locals, &mut output)?;
+        Ok(output.flush()?)
+    }
+
+    /// Gron without color, which is instead applied to whole lines.
+    fn gron(&self) -> Gron<'static> {
+        Gron::new()
+            .bracket_keys(self.bracket_keys)
+            .lossless_numbers(self.lossless_numbers)
+    }
+
+    fn values(
+        &self,
+        old: &Value<'_>,
+        new: &Value<'_>,
+        locals: &mut Locals<'_, Vec<u8>>,
+        output: &mut impl io::Write,
+    ) -> io::Result<()> {
+        match (old, new) {
+            (Value::Object(old), Value::Object(new)) => {
+                let mut old: Vec<(&str, &Value<'_>)> =
+                    old.iter().map(|(k, v)| (k.as_ref(), v)).collect();
+                let mut new: Vec<(&str, &Value<'_>)> =
+                    new.iter().map(|(k, v)| (k.as_ref(), v)).collect();
+                old.sort_unstable_by_key(|&(k, _)| k);
+                new.sort_unstable_by_key(|&(k, _)| k);
+                let (mut old, mut new) = (old.into_iter().peekable(), new.into_iter().peekable());
+                loop {
+                    let order = match (old.peek(), new.peek()) {
+                        (None, None) => return Ok(()),
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        (Some((old_key, _)), Some((new_key, _))) => old_key.cmp(new_key),
+                    };
+                    match order {
+                        Ordering::Less => {
+                            let (key, old) = old.next().unwrap();
+                            locals.push_key::<false>(key);
+                            self.write(old, "-", ANSI_REMOVED, locals, output)?;
+                        }
+                        Ordering::Greater => {
+                            let (key, new) = new.next().unwrap();
+                            locals.push_key::<false>(key);
+                            self.write(new, "+", ANSI_ADDED, locals, output)?;
+                        }
+                        Ordering::Equal => {
+                            let (key, old) = old.next().unwrap();
+                            let (_, new) = new.next().unwrap();
+                            locals.push_key::<false>(key);
+                            self.values(old, new, locals, output)?;
+                        }
+                    }
+                    locals.pop();
+                }
+            }
+            (Value::Array(old), Value::Array(new)) => {
+                let (old_matched, new_matched) = if self.ignore_array_order {
+                    self.match_elements(old, new)?
+                } else {
+                    (vec![false; old.len()], vec![false; new.len()])
+                };
+                for i in 0..old.len().max(new.len()) {
+                    locals.push_index::<false>(i);
+                    match (old.get(i), new.get(i)) {
+                        (Some(old), Some(new)) if !self.ignore_array_order => {
+                            self.values(old, new, locals, output)?;
+                        }
+                        (old, new) => {
+                            if let Some(old) = old.filter(|_| !old_matched[i]) {
+                                self.write(old, "-", ANSI_REMOVED, locals, output)?;
+                            }
+                            if let Some(new) = new.filter(|_| !new_matched[i]) {
+                                self.write(new, "+", ANSI_ADDED, locals, output)?;
+                            }
+                        }
+                    }
+                    locals.pop();
+                }
+                Ok(())
+            }
+            // Numbers kept as written are owned, and differ from strings with
+            // the same text.
+            (Value::String(s1), Value::String(s2))
+                if s1 == s2 && matches!(s1, Cow::Owned(_)) == matches!(s2, Cow::Owned(_)) =>
+            {
+                Ok(())
+            }
+            (Value::Static(old), Value::Static(new)) if same_static(old, new) => Ok(()),
+            _ => {
+                self.write(old, "-", ANSI_REMOVED, locals, output)?;
+                self.write(new, "+", ANSI_ADDED, locals, output)
+            }
+        }
+    }
+
+    /// Pairs up equal elements of two arrays, returning which elements of
+    /// each have a counterpart. Elements are compared by their gron lines.
+    fn match_elements(
+        &self,
+        old: &[Value<'_>],
+        new: &[Value<'_>],
+    ) -> io::Result<(Vec<bool>, Vec<bool>)> {
+        let lines = |json: &Value<'_>| -> io::Result<Vec<u8>> {
+            let mut locals = Locals::new(self.gron(), Vec::new());
+            process_recursively::<false, _>(json, &mut locals)?;
+            Ok(locals.output)
+        };
+        let mut unmatched: BTreeMap<Vec<u8>, Vec<usize>> = BTreeMap::new();
+        for (i, old) in old.iter().enumerate().rev() {
+            unmatched.entry(lines(old)?).or_default().push(i);
+        }
+        let mut old_matched = vec![false; old.len()];
+        let mut new_matched = vec![false; new.len()];
+        for (j, new) in new.iter().enumerate() {
+            if let Some(i) = unmatched.get_mut(&lines(new)?).and_then(Vec::pop) {
+                old_matched[i] = true;
+                new_matched[j] = true;
+            }
+        }
+        Ok((old_matched, new_matched))
+    }
+
+    /// Writes the gron lines of `json` at the current path, each prefixed by
+    /// `marker`.
+    fn write(
+        &self,
+        json: &Value<'_>,
+        marker: &str,
+        color: &str,
+        locals: &mut Locals<'_, Vec<u8>>,
+        output: &mut impl io::Write,
+    ) -> io::Result<()> {
+        process_recursively::<false, _>(json, locals)?;
+        // Strings are escaped, so each line ends at a newline.
+        for line in locals.output.split_inclusive(|&b| b == b'\n') {
+            let line = &line[..line.len() - 1];
+            if self.color {
+                output.write_all(color.as_bytes())?;
+                output.write_all(marker.as_bytes())?;
+                output.write_all(line)?;
+                writeln!(output, "{ANSI_RESET}")?;
+            } else {
+                output.write_all(marker.as_bytes())?;
+                output.write_all(line)?;
+                writeln!(output)?;
+            }
+        }
+        locals.output.clear();
+        Ok(())
+    }
+}
+
+fn same_static(old: &StaticNode, new: &StaticNode) -> bool {
+    match (old, new) {
+        // The equality of `StaticNode` is approximate for floats, but gron
+        // writes them exactly.
+        (StaticNode::F64(old), StaticNode::F64(new)) => old == new,
+        _ => old == new,
+    }
+}
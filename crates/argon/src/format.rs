@@ -0,0 +1,372 @@
+//! Formats other than json, which are converted to json before gronning and
+//! from the ungronned value after ungronning.
+
+use crate::{
+    gron::{escape, unescape, EscapeError},
+    ungron::{lossless_number, parse_number, Value},
+};
+use patched_simd_json::StaticNode;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write as _},
+    io,
+    path::Path,
+    str::FromStr,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Json,
+    /// Several documents in one stream become the elements of a top-level
+    /// array, which [`Ungron::jsonl`](crate::Ungron::jsonl) writes as
+    /// separate documents again.
+    Yaml,
+    /// Datetimes are read as strings. Writing fails for values toml cannot
+    /// represent, like `null` or a document that is not an object.
+    Toml,
+}
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "yaml" => Ok(Format::Yaml),
+            "toml" => Ok(Format::Toml),
+            _ => Err(format!("expected `json`, `yaml` or `toml`, got `{s}`")),
+        }
+    }
+}
+impl Format {
+    /// The format suggested by the extension of `path`, if any.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Format::Json),
+            "yaml" | "yml" => Some(Format::Yaml),
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+
+    /// Converts a document in this format to json, for gronning.
+    pub fn to_json(self, input: Vec<u8>) -> Result<Vec<u8>, Error> {
+        match self {
+            Format::Json => Ok(input),
+            Format::Yaml => yaml_to_json(&input).map_err(Error::Yaml),
+            Format::Toml => {
+                let toml = std::str::from_utf8(&input).map_err(Error::Utf8)?;
+                let table: toml::Table = toml::from_str(toml).map_err(Error::Toml)?;
+                let mut json = Vec::with_capacity(input.len());
+                write_toml_as_json(&mut json, &toml::Value::Table(table));
+                Ok(json)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Utf8(std::str::Utf8Error),
+    Yaml(serde_yaml::Error),
+    Toml(toml::de::Error),
+    TomlOutput(toml::ser::Error),
+    /// The value at `path` has no toml equivalent.
+    NotToml {
+        path: String,
+        reason: &'static str,
+    },
+    /// The string at `path`, or its key, has an invalid escape.
+    InvalidString {
+        path: String,
+        source: EscapeError,
+    },
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Utf8(err) => write!(f, "invalid utf-8: {err}"),
+            Error::Yaml(err) => write!(f, "invalid yaml: {err}"),
+            Error::Toml(err) => write!(f, "invalid toml: {err}"),
+            Error::TomlOutput(err) => write!(f, "could not write toml: {err}"),
+            Error::NotToml { path, reason } => {
+                write!(f, "cannot write `{path}` as toml: {reason}")
+            }
+            Error::InvalidString { path, source } => write!(f, "`{path}`: {source}"),
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Utf8(err) => Some(err),
+            Error::Yaml(err) => Some(err),
+            Error::Toml(err) => Some(err),
+            Error::TomlOutput(err) => Some(err),
+            Error::InvalidString { source, .. } => Some(source),
+            Error::NotToml { .. } => None,
+        }
+    }
+}
+
+fn yaml_to_json(yaml: &[u8]) -> Result<Vec<u8>, serde_yaml::Error> {
+    let mut documents = serde_yaml::Deserializer::from_slice(yaml)
+        .map(|document| {
+            let mut document = serde_yaml::Value::deserialize(document)?;
+            document.apply_merge()?;
+            Ok(document)
+        })
+        .collect::<Result<Vec<_>, serde_yaml::Error>>()?;
+    let mut json = Vec::with_capacity(yaml.len());
+    match documents.len() {
+        0 => json.extend_from_slice(b"null"),
+        1 => write_yaml_as_json(&mut json, &documents.pop().unwrap()),
+        _ => write_yaml_as_json(&mut json, &serde_yaml::Value::Sequence(documents)),
+    }
+    Ok(json)
+}
+
+fn write_yaml_as_json(json: &mut Vec<u8>, yaml: &serde_yaml::Value) {
+    use io::Write;
+    use serde_yaml::Value as Yaml;
+    match yaml {
+        Yaml::Null => json.extend_from_slice(b"null"),
+        Yaml::Bool(b) => write!(json, "{b}").unwrap(),
+        Yaml::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                write!(json, "{n}").unwrap();
+            } else if let Some(n) = n.as_i64() {
+                write!(json, "{n}").unwrap();
+            } else {
+                match n.as_f64() {
+                    // `{:?}` keeps the `.0` of integral floats.
+                    Some(f) if f.is_finite() => write!(json, "{f:?}").unwrap(),
+                    // Json has no infinities or NaN, so they are kept as
+                    // strings like `.inf`.
+                    _ => write_string(json, &n.to_string()),
+                }
+            }
+        }
+        Yaml::String(s) => write_string(json, s),
+        Yaml::Sequence(sequence) => {
+            json.push(b'[');
+            for (i, item) in sequence.iter().enumerate() {
+                if i > 0 {
+                    json.push(b',');
+                }
+                write_yaml_as_json(json, item);
+            }
+            json.push(b']');
+        }
+        Yaml::Mapping(mapping) => {
+            json.push(b'{');
+            for (i, (key, value)) in mapping.iter().enumerate() {
+                if i > 0 {
+                    json.push(b',');
+                }
+                match key {
+                    Yaml::String(key) => write_string(json, key),
+                    // Json keys are strings, so other keys are written as
+                    // they would be in yaml.
+                    _ => {
+                        let key = serde_yaml::to_string(key).unwrap();
+                        write_string(json, key.trim_end());
+                    }
+                }
+                json.push(b':');
+                write_yaml_as_json(json, value);
+            }
+            json.push(b'}');
+        }
+        Yaml::Tagged(tagged) => write_yaml_as_json(json, &tagged.value),
+    }
+}
+
+fn write_toml_as_json(json: &mut Vec<u8>, toml: &toml::Value) {
+    use io::Write;
+    use toml::Value as Toml;
+    match toml {
+        Toml::String(s) => write_string(json, s),
+        Toml::Integer(n) => write!(json, "{n}").unwrap(),
+        Toml::Float(f) if f.is_finite() => write!(json, "{f:?}").unwrap(),
+        // Kept as strings like `inf`, as for yaml.
+        Toml::Float(f) => write_string(json, &f.to_string()),
+        Toml::Boolean(b) => write!(json, "{b}").unwrap(),
+        Toml::Datetime(datetime) => write_string(json, &datetime.to_string()),
+        Toml::Array(array) => {
+            json.push(b'[');
+            for (i, item) in array.iter().enumerate() {
+                if i > 0 {
+                    json.push(b',');
+                }
+                write_toml_as_json(json, item);
+            }
+            json.push(b']');
+        }
+        Toml::Table(table) => {
+            json.push(b'{');
+            for (i, (key, value)) in table.iter().enumerate() {
+                if i > 0 {
+                    json.push(b',');
+                }
+                write_string(json, key);
+                json.push(b':');
+                write_toml_as_json(json, value);
+            }
+            json.push(b'}');
+        }
+    }
+}
+
+fn write_string(json: &mut Vec<u8>, s: &str) {
+    json.push(b'"');
+    json.extend_from_slice(escape(s).as_bytes());
+    json.push(b'"');
+}
+
+/// Writes an ungronned value as yaml. With `documents`, the elements of the
+/// top-level array are written as separate documents.
+pub(crate) fn write_yaml(
+    output: impl io::Write,
+    json: &Value<'_>,
+    sort_keys: bool,
+    documents: bool,
+) -> Result<(), Error> {
+    let mut serializer = serde_yaml::Serializer::new(output);
+    let (documents, stream) = match json {
+        Value::Array(array) if documents => (array.as_slice(), true),
+        _ => (std::slice::from_ref(json), false),
+    };
+    for (i, document) in documents.iter().enumerate() {
+        let mut path = if stream {
+            format!("json[{i}]")
+        } else {
+            String::from("json")
+        };
+        value_to_yaml(document, sort_keys, &mut path)?
+            .serialize(&mut serializer)
+            .map_err(Error::Yaml)?;
+    }
+    Ok(())
+}
+
+/// Converts `json` to yaml, with `path` being its gron path for errors.
+fn value_to_yaml(
+    json: &Value<'_>,
+    sort_keys: bool,
+    path: &mut String,
+) -> Result<serde_yaml::Value, Error> {
+    use serde_yaml::Value as Yaml;
+    if let Some(num) = lossless_number(json) {
+        // Numbers kept as written are parsed after all, as `serde_yaml` only
+        // writes numbers it has parsed.
+        return value_to_yaml(&Value::Static(parse_number(num).unwrap()), sort_keys, path);
+    }
+    Ok(match json {
+        Value::Static(StaticNode::Null) => Yaml::Null,
+        Value::Static(StaticNode::Bool(b)) => Yaml::Bool(*b),
+        Value::Static(StaticNode::I64(n)) => Yaml::Number((*n).into()),
+        Value::Static(StaticNode::U64(n)) => Yaml::Number((*n).into()),
+        Value::Static(StaticNode::F64(n)) => Yaml::Number((*n).into()),
+        Value::String(s) => Yaml::String(unescape_at(s, path)?.into_owned()),
+        Value::Array(array) => {
+            let mut items = Vec::with_capacity(array.len());
+            for (i, item) in array.iter().enumerate() {
+                let len = path.len();
+                write!(path, "[{i}]").unwrap();
+                items.push(value_to_yaml(item, sort_keys, path)?);
+                path.truncate(len);
+            }
+            Yaml::Sequence(items)
+        }
+        Value::Object(object) => {
+            let mut mapping = serde_yaml::Mapping::with_capacity(object.len());
+            for (key, value) in crate::ungron::entries(object, sort_keys) {
+                let len = path.len();
+                write!(path, "[\"{key}\"]").unwrap();
+                mapping.insert(
+                    Yaml::String(unescape_at(key, path)?.into_owned()),
+                    value_to_yaml(value, sort_keys, path)?,
+                );
+                path.truncate(len);
+            }
+            Yaml::Mapping(mapping)
+        }
+    })
+}
+
+/// Unescapes the string or key at `path`.
+fn unescape_at<'a>(s: &'a str, path: &str) -> Result<Cow<'a, str>, Error> {
+    unescape(s).map_err(|source| Error::InvalidString {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Formats an ungronned value as a toml document, which must be an object.
+pub(crate) fn to_toml(json: &Value<'_>, sort_keys: bool) -> Result<String, Error> {
+    let mut path = String::from("json");
+    let toml = match value_to_toml(json, sort_keys, &mut path)? {
+        toml::Value::Table(table) => table,
+        _ => {
+            return Err(Error::NotToml {
+                path,
+                reason: "a toml document is a table, so this must be an object",
+            })
+        }
+    };
+    toml::to_string(&toml).map_err(Error::TomlOutput)
+}
+
+/// Converts `json` to toml, with `path` being its gron path for errors.
+fn value_to_toml(
+    json: &Value<'_>,
+    sort_keys: bool,
+    path: &mut String,
+) -> Result<toml::Value, Error> {
+    use toml::Value as Toml;
+    let not_toml = |path: &String, reason| {
+        Err(Error::NotToml {
+            path: path.clone(),
+            reason,
+        })
+    };
+    if let Some(num) = lossless_number(json) {
+        return value_to_toml(&Value::Static(parse_number(num).unwrap()), sort_keys, path);
+    }
+    Ok(match json {
+        Value::Static(StaticNode::Null) => return not_toml(path, "toml has no null"),
+        Value::Static(StaticNode::Bool(b)) => Toml::Boolean(*b),
+        Value::Static(StaticNode::I64(n)) => Toml::Integer(*n),
+        Value::Static(StaticNode::U64(n)) => match i64::try_from(*n) {
+            Ok(n) => Toml::Integer(n),
+            Err(_) => return not_toml(path, "toml integers are at most 2^63 - 1"),
+        },
+        Value::Static(StaticNode::F64(n)) => Toml::Float(*n),
+        Value::String(s) => Toml::String(unescape_at(s, path)?.into_owned()),
+        Value::Array(array) => {
+            let mut items = Vec::with_capacity(array.len());
+            for (i, item) in array.iter().enumerate() {
+                let len = path.len();
+                write!(path, "[{i}]").unwrap();
+                items.push(value_to_toml(item, sort_keys, path)?);
+                path.truncate(len);
+            }
+            Toml::Array(items)
+        }
+        Value::Object(object) => {
+            let mut table = toml::Table::new();
+            for (key, value) in crate::ungron::entries(object, sort_keys) {
+                let len = path.len();
+                write!(path, "[\"{key}\"]").unwrap();
+                table.insert(
+                    unescape_at(key, path)?.into_owned(),
+                    value_to_toml(value, sort_keys, path)?,
+                );
+                path.truncate(len);
+            }
+            Toml::Table(table)
+        }
+    })
+}
@@ -1,34 +1,536 @@
-use patched_simd_json::value::borrowed::{self, Value};
+//! Converting json into assignment statements.
+
+use crate::select::{Cursor, Pattern};
+use patched_simd_json::{
+    value::borrowed::{self, Value},
+    StaticNode,
+};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+use regex::Regex;
 use std::{
     borrow::Cow,
     fmt,
-    io::{self, BufWriter},
-    mem::ManuallyDrop,
+    io::{self, BufWriter, Read},
+    ops::Range,
+    str::FromStr,
+    sync::OnceLock,
 };
 
-pub fn process(buf: &mut [u8], have_color: bool, output: Box<dyn io::Write>) -> Result<(), ()> {
-    let json = match borrowed::to_value(buf) {
-        Ok(json) => json,
-        Err(err) => {
-            tracing::error!(?err, "could not parse json");
-            return Err(());
+/// Converts json into assignment statements, one per line.
+///
+/// ```
+/// let mut json = br#"{"b":[true],"a":"x"}"#.to_vec();
+/// let mut output = Vec::new();
+/// argon::Gron::new().to_writer(&mut json, &mut output).unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "json = {};\njson.a = \"x\";\njson.b = [];\njson.b[0] = true;\n",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Gron<'a> {
+    color: bool,
+    bracket_keys: bool,
+    values: bool,
+    unescape: bool,
+    preserve_order: bool,
+    select: &'a [Pattern],
+    grep: Option<&'a Regex>,
+    grep_target: GrepTarget,
+    invert_match: bool,
+    ancestors: bool,
+    line_format: LineFormat,
+    path_syntax: PathSyntax,
+    gron_compat: bool,
+    lossless_numbers: bool,
+}
+impl<'a> Gron<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Color the output with ANSI escape codes.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Write keys that are not valid Javascript identifiers as `["key"]`
+    /// rather than `.key`, like gron does.
+    pub fn bracket_keys(mut self, bracket_keys: bool) -> Self {
+        self.bracket_keys = bracket_keys;
+        self
+    }
+
+    /// Write only the scalar values, one per line, with strings unquoted.
+    pub fn values(mut self, values: bool) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// With [`Gron::values`], also unescape the strings.
+    pub fn unescape(mut self, unescape: bool) -> Self {
+        self.unescape = unescape;
+        self
+    }
+
+    /// Write object keys in document order rather than sorted.
+    pub fn preserve_order(mut self, preserve_order: bool) -> Self {
+        self.preserve_order = preserve_order;
+        self
+    }
+
+    /// Write only the values matching any of these patterns, along with
+    /// everything below them. Subtrees that no pattern can match are skipped
+    /// without being formatted. No patterns selects everything.
+    ///
+    /// ```
+    /// let select = ["json.users[*].email".parse().unwrap()];
+    /// let mut json = br#"{"users":[{"email":"a@b","id":1}]}"#.to_vec();
+    /// let mut output = Vec::new();
+    /// argon::Gron::new()
+    ///     .select(&select)
+    ///     .to_writer(&mut json, &mut output)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "json.users[0].email = \"a@b\";\n",
+    /// );
+    /// ```
+    pub fn select(mut self, select: &'a [Pattern]) -> Self {
+        self.select = select;
+        self
+    }
+
+    /// Write only the lines matching this regex, tested against the
+    /// [`GrepTarget`] without color.
+    pub fn grep(mut self, grep: Option<&'a Regex>) -> Self {
+        self.grep = grep;
+        self
+    }
+
+    /// What [`Gron::grep`] is tested against.
+    pub fn grep_target(mut self, grep_target: GrepTarget) -> Self {
+        self.grep_target = grep_target;
+        self
+    }
+
+    /// With [`Gron::grep`], write the lines that do not match instead.
+    pub fn invert_match(mut self, invert_match: bool) -> Self {
+        self.invert_match = invert_match;
+        self
+    }
+
+    /// When filtering with [`Gron::select`] or [`Gron::grep`], also write the
+    /// lines of the objects and arrays containing written lines, so that the
+    /// output ungrons into values of the same types.
+    ///
+    /// ```
+    /// let grep = regex::Regex::new("b").unwrap();
+    /// let mut json = br#"{"a":[{"b":1}],"c":2}"#.to_vec();
+    /// let mut output = Vec::new();
+    /// argon::Gron::new()
+    ///     .grep(Some(&grep))
+    ///     .ancestors(true)
+    ///     .to_writer(&mut json, &mut output)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "json = {};\njson.a = [];\njson.a[0] = {};\njson.a[0].b = 1;\n",
+    /// );
+    /// ```
+    pub fn ancestors(mut self, ancestors: bool) -> Self {
+        self.ancestors = ancestors;
+        self
+    }
+
+    /// How to write each line. Records are never colored.
+    ///
+    /// ```
+    /// let mut json = br#"{"a":["x\ty",1]}"#.to_vec();
+    /// let mut output = Vec::new();
+    /// argon::Gron::new()
+    ///     .line_format(argon::gron::LineFormat::Tsv)
+    ///     .to_writer(&mut json, &mut output)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "json\tobject\t{}\njson.a\tarray\t[]\n\
+    ///      json.a[0]\tstring\tx\\ty\njson.a[1]\tnumber\t1\n",
+    /// );
+    /// ```
+    pub fn line_format(mut self, line_format: LineFormat) -> Self {
+        self.line_format = line_format;
+        self
+    }
+
+    /// How to write paths. [`Ungron`](crate::Ungron) reads all of them.
+    ///
+    /// ```
+    /// use argon::gron::PathSyntax;
+    /// let gron = |path_syntax| {
+    ///     let mut json = br#"{"a/b":[{"c'":1}]}"#.to_vec();
+    ///     let mut output = Vec::new();
+    ///     argon::Gron::new()
+    ///         .path_syntax(path_syntax)
+    ///         .to_writer(&mut json, &mut output)
+    ///         .unwrap();
+    ///     String::from_utf8(output).unwrap()
+    /// };
+    /// assert_eq!(
+    ///     gron(PathSyntax::Pointer),
+    ///     " = {};\n/a~1b = [];\n/a~1b/0 = {};\n/a~1b/0/c' = 1;\n",
+    /// );
+    /// assert_eq!(
+    ///     gron(PathSyntax::JsonPath),
+    ///     "$ = {};\n$['a/b'] = [];\n$['a/b'][0] = {};\n$['a/b'][0]['c\\''] = 1;\n",
+    /// );
+    /// ```
+    pub fn path_syntax(mut self, path_syntax: PathSyntax) -> Self {
+        self.path_syntax = path_syntax;
+        self
+    }
+
+    /// Write assignments exactly as gron does, with the keys that gron does
+    /// not consider identifiers as `["key"]`, strings escaped like gron and
+    /// object keys sorted like gron. Implies [`Gron::bracket_keys`] and
+    /// [`Gron::lossless_numbers`].
+    ///
+    /// ```
+    /// let mut json = br#"{"a\/b":"\/","b":1,"a":2}"#.to_vec();
+    /// let mut output = Vec::new();
+    /// argon::Gron::new()
+    ///     .gron_compat(true)
+    ///     .to_writer(&mut json, &mut output)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "json = {};\njson.a = 2;\njson.b = 1;\njson[\"a/b\"] = \"/\";\n",
+    /// );
+    /// ```
+    pub fn gron_compat(mut self, gron_compat: bool) -> Self {
+        self.gron_compat = gron_compat;
+        self
+    }
+
+    /// Write numbers exactly as they are written in the input, rather than
+    /// as parsed into a 64-bit integer or float. This keeps integers of any
+    /// size, trailing zeros and exponents, at the cost of copying the input
+    /// before parsing it.
+    ///
+    /// ```
+    /// let mut json = br#"[18446744073709551616,1.10,1e400]"#.to_vec();
+    /// let mut output = Vec::new();
+    /// argon::Gron::new()
+    ///     .lossless_numbers(true)
+    ///     .to_writer(&mut json, &mut output)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "json = [];\njson[0] = 18446744073709551616;\njson[1] = 1.10;\njson[2] = 1e400;\n",
+    /// );
+    /// ```
+    pub fn lossless_numbers(mut self, lossless_numbers: bool) -> Self {
+        self.lossless_numbers = lossless_numbers;
+        self
+    }
+
+    /// Whether numbers are written as in the input.
+    fn keeps_numbers(&self) -> bool {
+        self.lossless_numbers || self.gron_compat
+    }
+
+    /// Parses a json document for [`Gron::value_to_writer`], using `json` as
+    /// scratch space. With [`Gron::lossless_numbers`], the document is first
+    /// copied into `quoted` with its numbers quoted, and that is parsed
+    /// instead.
+    pub fn parse<'j>(
+        &self,
+        json: &'j mut [u8],
+        quoted: &'j mut Vec<u8>,
+    ) -> Result<Value<'j>, patched_simd_json::Error> {
+        if !self.keeps_numbers() {
+            let buf = json.as_ptr_range();
+            let json = borrowed::to_value(json)?;
+            if self.preserve_order {
+                assert_keys_within(&json, &buf);
+            }
+            return Ok(json);
         }
-    };
+        let numbers;
+        (*quoted, numbers) = quote_numbers(json);
+        let base = quoted.as_ptr() as usize;
+        let numbers: Vec<usize> = numbers.into_iter().map(|i| base + i).collect();
+        let buf = quoted.as_ptr_range();
+        let mut json = borrowed::to_value(quoted)?;
+        own_numbers(&mut json, &numbers);
+        if self.preserve_order {
+            assert_keys_within(&json, &buf);
+        }
+        Ok(json)
+    }
 
-    let mut locals = Locals::new(have_color, output);
-    if have_color {
-        process_recursively::<true>(&json, &mut locals);
-    } else {
-        process_recursively::<false>(&json, &mut locals);
+    /// Whether to color the output, which only applies to assignments.
+    pub(crate) fn colored(&self) -> bool {
+        self.color && self.line_format == LineFormat::Gron
     }
-    {
-        use io::Write;
-        locals.output.flush().unwrap();
+
+    /// Parses and grons a json document. The parser uses `json` as scratch
+    /// space.
+    pub fn to_writer(&self, json: &mut [u8], output: impl io::Write) -> Result<(), Error> {
+        let mut quoted = Vec::new();
+        let json = self.parse(json, &mut quoted).map_err(Error::Json)?;
+        self.value_to_writer(&json, output)
     }
 
-    // Leak `json` for quicker exit
-    let _ = ManuallyDrop::new(json);
-    Ok(())
+    /// Grons an already parsed document. Strings are expected to be escaped
+    /// the way the patched simd-json leaves them. With
+    /// [`Gron::lossless_numbers`], owned strings are taken to be numbers as
+    /// written. With [`Gron::preserve_order`], keys are ordered by their
+    /// addresses, so the document should come from [`Gron::parse`].
+    pub fn value_to_writer(&self, json: &Value<'_>, output: impl io::Write) -> Result<(), Error> {
+        let mut locals = Locals::new(*self, BufWriter::new(output));
+        if self.colored() {
+            process_recursively::<true, _>(json, &mut locals)?;
+        } else {
+            process_recursively::<false, _>(json, &mut locals)?;
+        }
+        Ok(locals.flush()?)
+    }
+
+    /// Grons newline-delimited json as if the documents were the elements of
+    /// an array, like `gron --stream`. Blank lines are skipped.
+    pub fn lines_to_writer(&self, jsonl: &mut [u8], output: impl io::Write) -> Result<(), Error> {
+        const LINES_PER_BATCH: usize = 1 << 12;
+
+        let mut lines: Vec<(usize, &mut [u8])> = jsonl
+            .split_mut(|&b| b == b'\n')
+            .enumerate()
+            .filter(|(_, line)| !line.iter().all(u8::is_ascii_whitespace))
+            .collect();
+
+        let mut root = Locals::new(*self, BufWriter::new(output));
+        if self.colored() {
+            root.write_empty_array::<true>()?;
+        } else {
+            root.write_empty_array::<false>()?;
+        }
+        for (batch_index, batch) in lines.chunks_mut(LINES_PER_BATCH).enumerate() {
+            let grons: Vec<Result<Vec<u8>, Error>> = batch
+                .par_iter_mut()
+                .enumerate()
+                .map(|(i, (line_index, line))| {
+                    let mut quoted = Vec::new();
+                    let json = self
+                        .parse(line, &mut quoted)
+                        .map_err(|source| Error::Line {
+                            line: *line_index + 1,
+                            source,
+                        })?;
+                    let index = batch_index * LINES_PER_BATCH + i;
+                    let mut locals = Locals::new(*self, Vec::new());
+                    if self.colored() {
+                        locals.push_index::<true>(index);
+                        process_recursively::<true, _>(&json, &mut locals)?;
+                    } else {
+                        locals.push_index::<false>(index);
+                        process_recursively::<false, _>(&json, &mut locals)?;
+                    }
+                    Ok(locals.output)
+                })
+                .collect();
+            for gron in grons {
+                root.write_with_ancestors(&gron?)?;
+            }
+        }
+        Ok(root.flush()?)
+    }
+
+    /// Grons several json documents as the values of a top-level object keyed
+    /// by their names, e.g. `json["a.json"].foo = 1;`. The documents are
+    /// parsed and gronned in parallel, each using its buffer as scratch space,
+    /// and are written in the given order.
+    pub fn named_to_writer<J: AsMut<[u8]> + Send>(
+        &self,
+        documents: &mut [(String, J)],
+        output: impl io::Write,
+    ) -> Result<(), Error> {
+        let grons: Vec<Vec<u8>> = documents
+            .par_iter_mut()
+            .map(|(name, json)| {
+                let mut quoted = Vec::new();
+                let json =
+                    self.parse(json.as_mut(), &mut quoted)
+                        .map_err(|source| Error::Document {
+                            name: name.clone(),
+                            source,
+                        })?;
+                let mut locals = Locals::new(*self, Vec::new());
+                if self.colored() {
+                    locals.push_name::<true>(name);
+                    process_recursively::<true, _>(&json, &mut locals)?;
+                } else {
+                    locals.push_name::<false>(name);
+                    process_recursively::<false, _>(&json, &mut locals)?;
+                }
+                Ok(locals.output)
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let mut root = Locals::new(*self, BufWriter::new(output));
+        if self.colored() {
+            root.write_empty_object::<true>()?;
+        } else {
+            root.write_empty_object::<false>()?;
+        }
+        for gron in grons {
+            root.write_with_ancestors(&gron)?;
+        }
+        Ok(root.flush()?)
+    }
+
+    /// Grons while reading the input, using memory bounded by the nesting
+    /// depth and the longest single string. Object keys are written in
+    /// document order, since sorting them would require holding the whole
+    /// object.
+    pub fn reader_to_writer(&self, input: impl Read, output: impl io::Write) -> Result<(), Error> {
+        crate::incremental::process(input, *self, output)
+    }
+}
+
+/// How [`Gron`] writes each line.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineFormat {
+    /// Assignments like `json.a = "x";`.
+    #[default]
+    Gron,
+    /// Tab-separated records of the path, the type and the value, like
+    /// `json.a<TAB>string<TAB>x`. Strings are written escaped but unquoted,
+    /// so the records contain no tabs or newlines of their own. The types are
+    /// `null`, `boolean`, `number`, `string`, `object` and `array`, and
+    /// objects and arrays have the values `{}` and `[]`.
+    /// [`Ungron`](crate::Ungron) reads these records as well as assignments.
+    Tsv,
+    /// Like [`LineFormat::Tsv`] but comma-separated, with strings unescaped
+    /// and fields quoted as needed by RFC 4180.
+    Csv,
+    /// Json arrays of the path, as an array of keys and indices, and the
+    /// value, like `[["a",0],"x"]`. These are unambiguous whatever the keys
+    /// contain, and [`Ungron`](crate::Ungron) reads them back exactly. The
+    /// [`PathSyntax`] does not apply.
+    Json,
+}
+impl FromStr for LineFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gron" => Ok(LineFormat::Gron),
+            "tsv" => Ok(LineFormat::Tsv),
+            "csv" => Ok(LineFormat::Csv),
+            "json" => Ok(LineFormat::Json),
+            _ => Err(format!(
+                "expected `gron`, `tsv`, `csv` or `json`, got `{s}`"
+            )),
+        }
+    }
+}
+
+/// How [`Gron`] writes paths. When ungronning paths other than
+/// [`PathSyntax::Gron`], keys whose escaping differs from json, like `~1` in
+/// a pointer or `\'` in a JSONPath, are copied, so they may come out of
+/// order unless [`Ungron::sort_keys`](crate::Ungron::sort_keys) is set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PathSyntax {
+    /// Javascript-like paths such as `json.a[0].b`.
+    #[default]
+    Gron,
+    /// RFC 6901 JSON Pointers such as `/a/0/b`, with `~` and `/` in keys
+    /// written as `~0` and `~1`. Keys are otherwise escaped as in json
+    /// strings, so that lines never break. The document itself is the empty
+    /// pointer, giving the line ` = {};`.
+    Pointer,
+    /// RFC 9535 normalized JSONPaths such as `$['a'][0]['b']`.
+    JsonPath,
+}
+impl FromStr for PathSyntax {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gron" => Ok(PathSyntax::Gron),
+            "pointer" => Ok(PathSyntax::Pointer),
+            "jsonpath" => Ok(PathSyntax::JsonPath),
+            _ => Err(format!(
+                "expected `gron`, `pointer` or `jsonpath`, got `{s}`"
+            )),
+        }
+    }
+}
+
+/// What [`Gron::grep`] is tested against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GrepTarget {
+    /// The line as written without color, like `json.a = "x";`.
+    #[default]
+    Line,
+    /// The path, like `json.a`.
+    Path,
+    /// The value, with strings unquoted but still escaped, like `x`. Objects
+    /// and arrays are `{}` and `[]`.
+    Value,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The input is not valid json.
+    Json(patched_simd_json::Error),
+    /// The input is not valid json, as found by [`Gron::reader_to_writer`].
+    Syntax {
+        offset: usize,
+        expected: &'static str,
+    },
+    /// A line of newline-delimited json is not valid json.
+    Line {
+        line: usize,
+        source: patched_simd_json::Error,
+    },
+    /// One of the documents given to [`Gron::named_to_writer`] is not valid
+    /// json.
+    Document {
+        name: String,
+        source: patched_simd_json::Error,
+    },
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Json(err) => write!(f, "invalid json: {err}"),
+            Error::Syntax { offset, expected } => {
+                write!(f, "byte {offset}: expected {expected}")
+            }
+            Error::Line { line, source } => write!(f, "line {line}: invalid json: {source}"),
+            Error::Document { name, source } => write!(f, "{name}: invalid json: {source}"),
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json(err)
+            | Error::Line { source: err, .. }
+            | Error::Document { source: err, .. } => Some(err),
+            Error::Syntax { .. } => None,
+        }
+    }
+}
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
 }
 
 const ANSI_KEY: &str = "\x1B[34m";
@@ -37,135 +539,912 @@ const ANSI_STR: &str = "\x1B[32m";
 const ANSI_BRACE: &str = "\x1B[35m";
 const ANSI_RESET: &str = "\x1B[0m";
 
-struct Locals {
-    output: BufWriter<Box<dyn io::Write>>,
+pub(crate) struct Locals<'a, W: io::Write> {
+    pub(crate) output: W,
     stack: String,
     stack_item_starts: Vec<usize>,
+    gron: Gron<'a>,
+    select: Option<Cursor<'a>>,
+    /// With [`Gron::ancestors`], the path lengths and braces of the objects
+    /// and arrays containing the current path that have not been written.
+    pending: Vec<(usize, &'static str)>,
+    /// Scratch space for what [`Gron::grep`] is tested against.
+    grep_text: String,
 }
-impl Locals {
-    fn new(color: bool, output: Box<dyn io::Write>) -> Self {
+impl<'a, W: io::Write> Locals<'a, W> {
+    pub(crate) fn new(gron: Gron<'a>, output: W) -> Self {
+        let root = match gron.path_syntax {
+            _ if gron.line_format == LineFormat::Json => "",
+            PathSyntax::Gron => "json",
+            PathSyntax::Pointer => "",
+            PathSyntax::JsonPath => "$",
+        };
         Self {
-            output: BufWriter::new(output),
-            stack: if color {
-                format!("{ANSI_KEY}json{ANSI_RESET}")
+            output,
+            stack: if gron.colored() && !root.is_empty() {
+                format!("{ANSI_KEY}{root}{ANSI_RESET}")
             } else {
-                "json".to_owned()
+                root.to_owned()
             },
             stack_item_starts: Vec::new(),
+            gron,
+            select: (!gron.select.is_empty()).then(|| Cursor::new(gron.select)),
+            pending: Vec::new(),
+            grep_text: String::new(),
         }
     }
-}
 
-fn process_recursively<const COLOR: bool>(json: &Value<'_>, locals: &mut Locals) {
-    match json {
-        Value::Static(val) => {
-            use io::Write;
+    /// Whether the path is needed, either for the output or for
+    /// [`Gron::grep`].
+    fn tracks_path(&self) -> bool {
+        !self.gron.values || self.gron.grep.is_some()
+    }
+
+    /// Whether to write the line assigning `value` to the current path, as
+    /// decided by [`Gron::select`] and [`Gron::grep`]. Strings are quoted by
+    /// `quote`.
+    fn keep(&mut self, value: impl fmt::Display, quote: &str) -> bool {
+        use fmt::Write;
+        if !self.is_selected() {
+            return false;
+        }
+        let Some(grep) = self.gron.grep else {
+            return true;
+        };
+        self.grep_text.clear();
+        if self.gron.grep_target != GrepTarget::Value {
+            if self.gron.colored() {
+                // Keys are escaped, so the only escape characters are those
+                // of the color codes.
+                let mut rest = &*self.stack;
+                while let Some(i) = rest.find('\x1B') {
+                    self.grep_text.push_str(&rest[..i]);
+                    rest = &rest[i + rest[i..].find('m').unwrap() + 1..];
+                }
+                self.grep_text.push_str(rest);
+            } else {
+                self.grep_text.push_str(&self.stack);
+            }
+        }
+        match self.gron.grep_target {
+            GrepTarget::Line => write!(&mut self.grep_text, " = {quote}{value}{quote};").unwrap(),
+            GrepTarget::Path => {}
+            GrepTarget::Value => write!(&mut self.grep_text, "{value}").unwrap(),
+        }
+        grep.is_match(&self.grep_text) != self.gron.invert_match
+    }
+
+    /// Writes the lines of the objects and arrays containing the current path
+    /// that have not yet been written.
+    fn write_ancestors(&mut self) -> io::Result<()> {
+        for (len, braces) in self.pending.drain(..) {
+            let path = &self.stack[..len];
+            if self.gron.line_format != LineFormat::Gron {
+                let kind = if braces == "{}" { "object" } else { "array" };
+                write_record(&mut self.output, self.gron.line_format, path, kind, braces)?;
+            } else if self.gron.colored() {
+                writeln!(self.output, "{path} = {ANSI_BRACE}{braces}{ANSI_RESET};")?;
+            } else {
+                writeln!(self.output, "{path} = {braces};")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes lines gronned by a separate [`Locals`] below the current path,
+    /// along with the ancestors they need.
+    fn write_with_ancestors(&mut self, lines: &[u8]) -> io::Result<()> {
+        if !lines.is_empty() {
+            self.write_ancestors()?;
+            self.output.write_all(lines)?;
+        }
+        Ok(())
+    }
+
+    /// Keeps the line of an object or array that [`Locals::keep`] rejected,
+    /// in case lines below it are written.
+    fn defer(&mut self, braces: &'static str) {
+        if self.gron.ancestors {
+            self.pending.push((self.stack.len(), braces));
+        }
+    }
+
+    /// Whether the current path is selected by [`Gron::select`].
+    fn is_selected(&self) -> bool {
+        self.select.as_ref().is_none_or(Cursor::is_matched)
+    }
+
+    /// Whether nothing at or below the current path is selected by
+    /// [`Gron::select`].
+    pub(crate) fn is_pruned(&self) -> bool {
+        self.select.as_ref().is_some_and(Cursor::is_pruned)
+    }
+
+    /// Writes the current path with the type and value of a line in a
+    /// [`LineFormat`] other than [`LineFormat::Gron`].
+    fn write_record(&mut self, kind: &str, value: &str) -> io::Result<()> {
+        write_record(
+            &mut self.output,
+            self.gron.line_format,
+            &self.stack,
+            kind,
+            value,
+        )
+    }
+
+    pub(crate) fn flush(&mut self) -> io::Result<()> {
+        self.output.flush()
+    }
+
+    pub(crate) fn write_static<const COLOR: bool>(&mut self, val: &StaticNode) -> io::Result<()> {
+        let kind = match val {
+            StaticNode::Null => "null",
+            StaticNode::Bool(_) => "boolean",
+            _ => "number",
+        };
+        self.write_scalar::<COLOR>(val, kind)
+    }
+
+    /// Writes a number as written in the input with
+    /// [`Gron::lossless_numbers`], and otherwise as parsed. Returns `false`
+    /// without writing it if it is out of range, like the patched simd-json.
+    pub(crate) fn write_number<const COLOR: bool>(&mut self, num: &str) -> io::Result<bool> {
+        if self.gron.keeps_numbers() {
+            self.write_scalar::<COLOR>(num, "number")?;
+        } else {
+            let Some(val) = parse_in_range(num) else {
+                return Ok(false);
+            };
+            self.write_static::<COLOR>(&val)?;
+        }
+        Ok(true)
+    }
+
+    /// Writes a value other than a string or container, of the given record
+    /// type.
+    fn write_scalar<const COLOR: bool>(
+        &mut self,
+        val: impl fmt::Display,
+        kind: &str,
+    ) -> io::Result<()> {
+        if !self.keep(&val, "") {
+            return Ok(());
+        }
+        self.write_ancestors()?;
+        if self.gron.values {
             if COLOR {
-                writeln!(
-                    locals.output,
-                    "{} = {ANSI_NUM}{val}{ANSI_RESET};",
-                    locals.stack
-                )
-                .unwrap();
+                writeln!(self.output, "{ANSI_NUM}{val}{ANSI_RESET}")?;
             } else {
-                writeln!(locals.output, "{} = {val};", locals.stack).unwrap();
+                writeln!(self.output, "{val}")?;
             }
+            return Ok(());
         }
-        Value::String(val) => {
-            let val = escape_c1_control_codes(val);
-            use io::Write;
+        if self.gron.line_format != LineFormat::Gron {
+            return self.write_record(kind, &val.to_string());
+        }
+        if COLOR {
+            writeln!(self.output, "{} = {ANSI_NUM}{val}{ANSI_RESET};", self.stack)?;
+        } else {
+            writeln!(self.output, "{} = {val};", self.stack)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_string<const COLOR: bool>(&mut self, val: &str) -> io::Result<()> {
+        let quote = if self.gron.grep_target == GrepTarget::Line {
+            "\""
+        } else {
+            ""
+        };
+        if !self.keep(val, quote) {
+            return Ok(());
+        }
+        self.write_ancestors()?;
+        if self.gron.values {
+            let val = if self.gron.unescape {
+                unescape_parsed(val)
+            } else {
+                escape_c1_control_codes(val)
+            };
             if COLOR {
-                writeln!(
-                    locals.output,
-                    "{} = \"{ANSI_STR}{val}{ANSI_RESET}\";",
-                    locals.stack
-                )
-                .unwrap();
+                writeln!(self.output, "{ANSI_STR}{val}{ANSI_RESET}")?;
             } else {
-                writeln!(locals.output, "{} = \"{val}\";", locals.stack).unwrap();
+                writeln!(self.output, "{val}")?;
             }
+            return Ok(());
         }
-        Value::Array(array) => {
-            {
-                use io::Write;
+        match self.gron.line_format {
+            LineFormat::Csv => return self.write_record("string", &unescape_parsed(val)),
+            LineFormat::Json => {
+                let path = json_path(&self.stack);
+                return writeln!(self.output, "[[{path}],\"{val}\"]");
+            }
+            LineFormat::Gron | LineFormat::Tsv => {}
+        }
+        let val = if self.gron.gron_compat {
+            gron_escape(val)
+        } else {
+            escape_c1_control_codes(val)
+        };
+        if self.gron.line_format == LineFormat::Tsv {
+            return self.write_record("string", &val);
+        }
+        if COLOR {
+            writeln!(
+                self.output,
+                "{} = \"{ANSI_STR}{val}{ANSI_RESET}\";",
+                self.stack
+            )?;
+        } else {
+            writeln!(self.output, "{} = \"{val}\";", self.stack)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_empty_array<const COLOR: bool>(&mut self) -> io::Result<()> {
+        if self.gron.values {
+            return Ok(());
+        }
+        if !self.keep("[]", "") {
+            self.defer("[]");
+            return Ok(());
+        }
+        self.write_ancestors()?;
+        if self.gron.line_format != LineFormat::Gron {
+            return self.write_record("array", "[]");
+        }
+        if COLOR {
+            writeln!(self.output, "{} = {ANSI_BRACE}[]{ANSI_RESET};", self.stack)?;
+        } else {
+            writeln!(self.output, "{} = [];", self.stack)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_empty_object<const COLOR: bool>(&mut self) -> io::Result<()> {
+        if self.gron.values {
+            return Ok(());
+        }
+        if !self.keep("{}", "") {
+            self.defer("{}");
+            return Ok(());
+        }
+        self.write_ancestors()?;
+        if self.gron.line_format != LineFormat::Gron {
+            return self.write_record("object", "{}");
+        }
+        if COLOR {
+            writeln!(
+                self.output,
+                "{} = {ANSI_BRACE}{{}}{ANSI_RESET};",
+                self.stack
+            )?;
+        } else {
+            writeln!(self.output, "{} = {{}};", self.stack)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn push_index<const COLOR: bool>(&mut self, i: usize) {
+        use fmt::Write;
+        if let Some(select) = &mut self.select {
+            select.push_index(i);
+        }
+        if !self.tracks_path() {
+            return;
+        }
+        self.stack_item_starts.push(self.stack.len());
+        if self.is_pruned() {
+            return;
+        }
+        if self.gron.line_format == LineFormat::Json {
+            write!(&mut self.stack, ",{i}").unwrap();
+            return;
+        }
+        match (COLOR, self.gron.path_syntax) {
+            (true, PathSyntax::Pointer) => {
+                write!(&mut self.stack, "/{ANSI_NUM}{i}{ANSI_RESET}").unwrap()
+            }
+            (false, PathSyntax::Pointer) => write!(&mut self.stack, "/{i}").unwrap(),
+            (true, _) => write!(
+                &mut self.stack,
+                "{ANSI_BRACE}[{ANSI_NUM}{i}{ANSI_BRACE}]{ANSI_RESET}"
+            )
+            .unwrap(),
+            (false, _) => write!(&mut self.stack, "[{i}]").unwrap(),
+        }
+    }
+
+    pub(crate) fn push_key<const COLOR: bool>(&mut self, key: &str) {
+        use fmt::Write;
+        if let Some(select) = &mut self.select {
+            select.push_key(key);
+        }
+        if !self.tracks_path() {
+            return;
+        }
+        self.stack_item_starts.push(self.stack.len());
+        if self.is_pruned() {
+            return;
+        }
+        if !self.gron_paths() {
+            return self.push_other_key::<COLOR>(key);
+        }
+        let bracket = (self.gron.bracket_keys || self.gron.gron_compat) && !is_identifier(key);
+        let key = if self.gron.gron_compat {
+            gron_escape(key)
+        } else {
+            escape_c1_control_codes(key)
+        };
+        match (COLOR, bracket) {
+            (true, true) => write!(
+                &mut self.stack,
+                "{ANSI_BRACE}[{ANSI_STR}\"{key}\"{ANSI_BRACE}]{ANSI_RESET}"
+            )
+            .unwrap(),
+            (true, false) => write!(&mut self.stack, ".{ANSI_KEY}{key}{ANSI_RESET}").unwrap(),
+            (false, true) => write!(&mut self.stack, "[\"{key}\"]").unwrap(),
+            (false, false) => write!(&mut self.stack, ".{key}").unwrap(),
+        }
+    }
+
+    /// Pushes the name of a document given to [`Gron::named_to_writer`],
+    /// always as `["name"]` since names are rarely identifiers.
+    fn push_name<const COLOR: bool>(&mut self, name: &str) {
+        use fmt::Write;
+        let name = escape(name);
+        if let Some(select) = &mut self.select {
+            select.push_key(&name);
+        }
+        if !self.tracks_path() {
+            return;
+        }
+        self.stack_item_starts.push(self.stack.len());
+        if self.is_pruned() {
+            return;
+        }
+        if !self.gron_paths() {
+            return self.push_other_key::<COLOR>(&name);
+        }
+        let name = if self.gron.gron_compat {
+            gron_escape(&name)
+        } else {
+            escape_c1_control_codes(&name)
+        };
+        if COLOR {
+            write!(
+                &mut self.stack,
+                "{ANSI_BRACE}[{ANSI_STR}\"{name}\"{ANSI_BRACE}]{ANSI_RESET}"
+            )
+            .unwrap();
+        } else {
+            write!(&mut self.stack, "[\"{name}\"]").unwrap();
+        }
+    }
+
+    /// Whether paths are written in [`PathSyntax::Gron`].
+    fn gron_paths(&self) -> bool {
+        self.gron.path_syntax == PathSyntax::Gron && self.gron.line_format != LineFormat::Json
+    }
+
+    /// Pushes a key in a [`PathSyntax`] other than [`PathSyntax::Gron`], or
+    /// for [`LineFormat::Json`].
+    fn push_other_key<const COLOR: bool>(&mut self, key: &str) {
+        use fmt::Write;
+        match self.gron.path_syntax {
+            _ if self.gron.line_format == LineFormat::Json => {
+                write!(&mut self.stack, ",\"{key}\"").unwrap();
+            }
+            PathSyntax::Gron => unreachable!(),
+            PathSyntax::Pointer => {
+                let key = pointer_token(key);
                 if COLOR {
-                    writeln!(
-                        locals.output,
-                        "{} = {ANSI_BRACE}[]{ANSI_RESET};",
-                        locals.stack
+                    write!(&mut self.stack, "/{ANSI_KEY}{key}{ANSI_RESET}").unwrap();
+                } else {
+                    write!(&mut self.stack, "/{key}").unwrap();
+                }
+            }
+            PathSyntax::JsonPath => {
+                let key = json_path_key(key);
+                if COLOR {
+                    write!(
+                        &mut self.stack,
+                        "{ANSI_BRACE}[{ANSI_STR}'{key}'{ANSI_BRACE}]{ANSI_RESET}"
                     )
                     .unwrap();
                 } else {
-                    writeln!(locals.output, "{} = [];", locals.stack).unwrap();
+                    write!(&mut self.stack, "['{key}']").unwrap();
                 }
             }
-            {
-                use fmt::Write;
-                for (i, item) in array.iter().enumerate() {
-                    {
-                        locals.stack_item_starts.push(locals.stack.len());
-                        if COLOR {
-                            write!(
-                                &mut locals.stack,
-                                "{ANSI_BRACE}[{ANSI_NUM}{i}{ANSI_BRACE}]{ANSI_RESET}"
-                            )
-                            .unwrap();
-                        } else {
-                            write!(&mut locals.stack, "[{i}]").unwrap();
-                        }
-                    }
-                    process_recursively::<COLOR>(item, locals);
-                    {
-                        locals
-                            .stack
-                            .truncate(locals.stack_item_starts.pop().unwrap());
-                    }
+        }
+    }
+
+    pub(crate) fn pop(&mut self) {
+        if let Some(select) = &mut self.select {
+            select.pop();
+        }
+        if !self.tracks_path() {
+            return;
+        }
+        self.stack.truncate(self.stack_item_starts.pop().unwrap());
+        while self
+            .pending
+            .last()
+            .is_some_and(|&(len, _)| len > self.stack.len())
+        {
+            self.pending.pop();
+        }
+    }
+}
+
+pub(crate) fn process_recursively<const COLOR: bool, W: io::Write>(
+    json: &Value<'_>,
+    locals: &mut Locals<W>,
+) -> io::Result<()> {
+    match json {
+        Value::Static(val) => locals.write_static::<COLOR>(val)?,
+        Value::String(Cow::Owned(num)) if locals.gron.keeps_numbers() => {
+            locals.write_scalar::<COLOR>(num, "number")?
+        }
+        Value::String(val) => locals.write_string::<COLOR>(val)?,
+        Value::Array(array) => {
+            locals.write_empty_array::<COLOR>()?;
+            for (i, item) in array.iter().enumerate() {
+                locals.push_index::<COLOR>(i);
+                if !locals.is_pruned() {
+                    process_recursively::<COLOR, W>(item, locals)?;
                 }
+                locals.pop();
             }
         }
         Value::Object(object) => {
-            {
-                use io::Write;
-                if COLOR {
-                    writeln!(
-                        locals.output,
-                        "{} = {ANSI_BRACE}{{}}{ANSI_RESET};",
-                        locals.stack
-                    )
-                    .unwrap();
-                } else {
-                    writeln!(locals.output, "{} = {{}};", locals.stack).unwrap();
+            locals.write_empty_object::<COLOR>()?;
+            let mut object: Vec<(&str, &Value<'_>)> =
+                object.iter().map(|(k, v)| (k.as_ref(), v)).collect();
+            if locals.gron.preserve_order {
+                // See `assert_keys_within`.
+                object.sort_unstable_by_key(|&(k, _)| k.as_ptr());
+            } else if locals.gron.gron_compat {
+                object.sort_by_cached_key(|&(k, _)| gron_sort_key(k));
+            } else {
+                object.sort_unstable_by_key(|&(k, _)| k);
+            }
+            for (key, value) in object {
+                locals.push_key::<COLOR>(key);
+                if !locals.is_pruned() {
+                    process_recursively::<COLOR, W>(value, locals)?;
                 }
+                locals.pop();
             }
-            {
+        }
+    }
+    Ok(())
+}
+
+fn write_record(
+    output: &mut impl io::Write,
+    line_format: LineFormat,
+    path: &str,
+    kind: &str,
+    value: &str,
+) -> io::Result<()> {
+    match line_format {
+        LineFormat::Gron => unreachable!(),
+        LineFormat::Tsv => writeln!(output, "{path}\t{kind}\t{value}"),
+        LineFormat::Csv => writeln!(output, "{},{kind},{}", csv_field(path), csv_field(value)),
+        // Strings are written by `Locals::write_string`.
+        LineFormat::Json => writeln!(output, "[[{}],{value}]", json_path(path)),
+    }
+}
+
+/// The keys and indices of a path for [`LineFormat::Json`], which are kept
+/// with a leading comma each.
+fn json_path(path: &str) -> &str {
+    path.strip_prefix(',').unwrap_or(path)
+}
+
+/// Quotes a csv field if it contains a separator, quote or line break.
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if !field.contains([',', '"', '\n', '\r']) {
+        return Cow::Borrowed(field);
+    }
+    Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+}
+
+/// Whether `key` can be written as `.key` in Javascript, using the same rules
+/// as gron. Escaped keys contain a backslash and are therefore never
+/// identifiers.
+fn is_identifier(key: &str) -> bool {
+    const RESERVED: &[&str] = &[
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "new",
+        "null",
+        "return",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+        "yield",
+    ];
+    if key.is_ascii() {
+        let mut bytes = key.bytes();
+        let Some(first) = bytes.next() else {
+            return false;
+        };
+        let is_first = |b: u8| b.is_ascii_alphabetic() || b == b'$' || b == b'_';
+        return is_first(first)
+            && bytes.all(|b| is_first(b) || b.is_ascii_digit())
+            && !RESERVED.contains(&key);
+    }
+    // The unicode categories gron allows, which are not quite those of
+    // `char::is_alphabetic`.
+    static IDENTIFIER: OnceLock<Regex> = OnceLock::new();
+    IDENTIFIER
+        .get_or_init(|| {
+            const FIRST: &str = r"\p{Lu}\p{Ll}\p{Lm}\p{Lo}\p{Nl}$_";
+            Regex::new(&format!(
+                r"^[{FIRST}][{FIRST}\p{{Mn}}\p{{Mc}}\p{{Nd}}\p{{Pc}}]*$"
+            ))
+            .unwrap()
+        })
+        .is_match(key)
+}
+
+/// The order in which gron sorts object keys, which compares keys as they are
+/// written. Every `.key` thus comes before every `["key"]`, and `["a b"]`
+/// before `["a"]` since a space sorts before the closing quote.
+fn gron_sort_key(key: &str) -> (bool, Cow<'_, str>) {
+    if is_identifier(key) {
+        (false, Cow::Borrowed(key))
+    } else {
+        (true, Cow::Owned(format!("\"{}\"", gron_escape(key))))
+    }
+}
+
+/// Writes an escaped key as a JSON Pointer reference token, which stays
+/// escaped as a json string but in a canonical way, so that `\/` is `~1`.
+fn pointer_token(key: &str) -> Cow<'_, str> {
+    let mut token = match unescape(key) {
+        Ok(Cow::Owned(unescaped)) => Cow::Owned(escape(&unescaped).into_owned()),
+        // Unchecked escapes are kept as written, like in other keys.
+        _ => Cow::Borrowed(key),
+    };
+    if let Cow::Owned(escaped) = escape_c1_control_codes(&token) {
+        token = Cow::Owned(escaped);
+    }
+    if !token.contains(['~', '/']) {
+        return token;
+    }
+    Cow::Owned(token.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Writes an escaped key as the contents of a single-quoted name in a
+/// normalized JSONPath.
+fn json_path_key(key: &str) -> Cow<'_, str> {
+    if !key.contains(['\\', '\'']) {
+        return Cow::Borrowed(key);
+    }
+    let mut ret = String::with_capacity(key.len() + 2);
+    for c in unescape_parsed(key).chars() {
+        match c {
+            '\'' => ret.push_str("\\'"),
+            '\\' => ret.push_str("\\\\"),
+            '\u{8}' => ret.push_str("\\b"),
+            '\u{c}' => ret.push_str("\\f"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if c < ' ' => {
                 use fmt::Write;
-                let mut object: Vec<(&str, &Value<'_>)> =
-                    object.iter().map(|(k, v)| (k.as_ref(), v)).collect();
-                object.sort_unstable_by_key(|&(k, _)| k);
-                for (key, value) in object {
-                    {
-                        locals.stack_item_starts.push(locals.stack.len());
-                        let dot = if locals.stack.is_empty() { "" } else { "." };
-                        let key = escape_c1_control_codes(key);
-                        if COLOR {
-                            write!(&mut locals.stack, "{dot}{ANSI_KEY}{key}{ANSI_RESET}").unwrap();
-                        } else {
-                            write!(&mut locals.stack, "{dot}{key}").unwrap();
-                        }
-                    }
-                    process_recursively::<COLOR>(value, locals);
-                    {
-                        locals
-                            .stack
-                            .truncate(locals.stack_item_starts.pop().unwrap());
+                write!(&mut ret, "\\u{:04x}", u32::from(c)).unwrap();
+            }
+            c => ret.push(c),
+        }
+    }
+    Cow::Owned(ret)
+}
+
+/// Copies `json` with its number literals quoted, so that the parser keeps
+/// them as strings. Returns the copy and the offsets of the quoted literals.
+/// Invalid literals are left unquoted for the parser to reject.
+pub(crate) fn quote_numbers(json: &[u8]) -> (Vec<u8>, Vec<usize>) {
+    let mut quoted = Vec::with_capacity(json.len() + json.len() / 4);
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < json.len() {
+        match json[i] {
+            b'"' => {
+                let mut end = i + 1;
+                loop {
+                    match memchr::memchr2(b'"', b'\\', &json[end..]) {
+                        Some(j) if json[end + j] == b'\\' => end = (end + j + 2).min(json.len()),
+                        Some(j) => break end += j + 1,
+                        None => break end = json.len(),
                     }
                 }
+                quoted.extend_from_slice(&json[i..end]);
+                i = end;
+            }
+            b'-' | b'0'..=b'9' => {
+                let len = json[i..]
+                    .iter()
+                    .take_while(|b| matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'))
+                    .count();
+                let num = &json[i..i + len];
+                if is_json_number(num) {
+                    quoted.push(b'"');
+                    numbers.push(quoted.len());
+                    quoted.extend_from_slice(num);
+                    quoted.push(b'"');
+                } else {
+                    quoted.extend_from_slice(num);
+                }
+                i += len;
+            }
+            b => {
+                quoted.push(b);
+                i += 1;
+            }
+        }
+    }
+    (quoted, numbers)
+}
+
+/// Replaces the strings starting at the sorted addresses `numbers`, which are
+/// quoted numbers, by owned copies. Owned strings thus mark numbers as
+/// written, since the parser borrows all other strings from its input.
+/// Checks what [`Gron::preserve_order`] relies on: the parser borrows every
+/// key in place from the one buffer it parsed, so the addresses of the keys
+/// follow the document order. The objects themselves do not keep it past a
+/// few entries.
+fn assert_keys_within(json: &Value<'_>, buf: &Range<*const u8>) {
+    match json {
+        Value::Array(array) => array.iter().for_each(|item| assert_keys_within(item, buf)),
+        Value::Object(object) => object.iter().for_each(|(key, value)| {
+            assert!(
+                matches!(key, Cow::Borrowed(_)) && buf.contains(&key.as_ptr()),
+                "the parser should leave keys in place",
+            );
+            assert_keys_within(value, buf);
+        }),
+        _ => {}
+    }
+}
+
+pub(crate) fn own_numbers(json: &mut Value<'_>, numbers: &[usize]) {
+    match json {
+        Value::String(s) if numbers.binary_search(&(s.as_ptr() as usize)).is_ok() => {
+            *s = Cow::Owned(s.to_string());
+        }
+        Value::Array(array) => array.iter_mut().for_each(|item| own_numbers(item, numbers)),
+        Value::Object(object) => object
+            .iter_mut()
+            .for_each(|(_, value)| own_numbers(value, numbers)),
+        _ => {}
+    }
+}
+
+/// Parses a json number the way the patched simd-json does, which rejects
+/// integers that do not fit in 64 bits and floats that overflow.
+fn parse_in_range(num: &str) -> Option<StaticNode> {
+    let val = crate::ungron::parse_number(num)?;
+    match val {
+        StaticNode::F64(float) if !float.is_finite() => None,
+        StaticNode::F64(_) if !num.contains(['.', 'e', 'E']) => None,
+        _ => Some(val),
+    }
+}
+
+/// `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`
+pub(crate) fn is_json_number(mut num: &[u8]) -> bool {
+    fn digits(num: &mut &[u8]) -> bool {
+        let len = num.iter().take_while(|b| b.is_ascii_digit()).count();
+        *num = &num[len..];
+        len > 0
+    }
+    if let [b'-', rest @ ..] = num {
+        num = rest;
+    }
+    match num {
+        [b'0', rest @ ..] => num = rest,
+        [b'1'..=b'9', ..] => {
+            digits(&mut num);
+        }
+        _ => return false,
+    }
+    if let [b'.', rest @ ..] = num {
+        num = rest;
+        if !digits(&mut num) {
+            return false;
+        }
+    }
+    if let [b'e' | b'E', rest @ ..] = num {
+        num = rest;
+        if let [b'+' | b'-', rest @ ..] = num {
+            num = rest;
+        }
+        if !digits(&mut num) {
+            return false;
+        }
+    }
+    num.is_empty()
+}
+
+/// Escapes a string the way the patched simd-json leaves it.
+pub(crate) fn escape(s: &str) -> Cow<'_, str> {
+    if !s.contains(|c: char| c < ' ' || c == '"' || c == '\\' || c == '\u{7f}') {
+        return Cow::Borrowed(s);
+    }
+    let mut ret = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\u{8}' => ret.push_str("\\b"),
+            '\u{c}' => ret.push_str("\\f"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if c < ' ' || c == '\u{7f}' => {
+                use fmt::Write;
+                write!(&mut ret, "\\u{:04X}", u32::from(c)).unwrap();
+            }
+            c => ret.push(c),
+        }
+    }
+    Cow::Owned(ret)
+}
+
+/// Escapes an escaped string the way gron does, which unlike the patched
+/// simd-json unescapes `\/` and escapes DEL, the C1 control codes and the
+/// line and paragraph separators.
+fn gron_escape(s: &str) -> Cow<'_, str> {
+    if !s.contains("\\/") && !s.contains(['\u{7f}', '\u{2028}', '\u{2029}']) {
+        return escape_c1_control_codes_with(s, b'A');
+    }
+    let mut ret = String::with_capacity(s.len() + 8);
+    for c in unescape_parsed(s).chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\u{8}' => ret.push_str("\\b"),
+            '\u{c}' => ret.push_str("\\f"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if c.is_control() || c == '\u{2028}' || c == '\u{2029}' => {
+                use fmt::Write;
+                write!(&mut ret, "\\u{:04X}", u32::from(c)).unwrap();
             }
+            c => ret.push(c),
         }
     }
+    Cow::Owned(ret)
+}
+
+/// Undoes the escaping of a string as kept by the patched simd-json, or as
+/// written in lines to ungron. Fails on escapes that json does not have and on
+/// `\u` escapes of surrogates that are not part of a pair.
+pub(crate) fn unescape(s: &str) -> Result<Cow<'_, str>, EscapeError> {
+    if !s.contains('\\') {
+        return Ok(Cow::Borrowed(s));
+    }
+    let mut ret = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('\\') {
+        ret.push_str(&rest[..i]);
+        let escape = &rest[i..];
+        let (c, len) = match escape.as_bytes().get(1) {
+            Some(b'b') => ('\u{8}', 2),
+            Some(b'f') => ('\u{c}', 2),
+            Some(b'n') => ('\n', 2),
+            Some(b'r') => ('\r', 2),
+            Some(b't') => ('\t', 2),
+            Some(b'u') => unescape_unicode(escape)?,
+            Some(&c @ (b'"' | b'\\' | b'/')) => (char::from(c), 2),
+            _ => return Err(EscapeError::new(escape, 2, "one of `\"\\/bfnrtu`")),
+        };
+        ret.push(c);
+        rest = &escape[len..];
+    }
+    ret.push_str(rest);
+    Ok(Cow::Owned(ret))
 }
 
-fn escape_c1_control_codes<'a>(mut s: &'a str) -> Cow<'a, str> {
+/// Decodes the `\uXXXX` escape that `s` starts with, or the surrogate pair of
+/// two such escapes, into a character and the length of its escape.
+fn unescape_unicode(s: &str) -> Result<(char, usize), EscapeError> {
+    let hex = |at: usize| {
+        s.get(at..at + 4)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+    };
+    let Some(unit) = hex(2) else {
+        return Err(EscapeError::new(s, 6, "four hex digits"));
+    };
+    let (unit, len) = match unit {
+        0xD800..=0xDBFF => match s[6..].starts_with("\\u").then(|| hex(8)).flatten() {
+            Some(low @ 0xDC00..=0xDFFF) => (0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00), 12),
+            _ => return Err(EscapeError::new(s, 6, "a low surrogate escape after it")),
+        },
+        0xDC00..=0xDFFF => return Err(EscapeError::new(s, 6, "a high surrogate escape before it")),
+        _ => (unit, 6),
+    };
+    // Every other code point below 0x110000 is a character.
+    Ok((char::from_u32(unit).unwrap(), len))
+}
+
+/// An escape that [`unescape`] cannot undo.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscapeError {
+    /// The escape, from its backslash up to where it is invalid.
+    pub escape: String,
+    pub expected: &'static str,
+}
+impl EscapeError {
+    fn new(s: &str, len: usize, expected: &'static str) -> Self {
+        let mut len = len.min(s.len());
+        while !s.is_char_boundary(len) {
+            len += 1;
+        }
+        EscapeError {
+            escape: s[..len].to_owned(),
+            expected,
+        }
+    }
+}
+impl fmt::Display for EscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid escape `{}`: expected {}",
+            self.escape, self.expected
+        )
+    }
+}
+impl std::error::Error for EscapeError {}
+
+/// Undoes the escaping of a string from parsed json. The patched simd-json
+/// checks `\u` escapes but keeps others unchecked, so a string with an invalid
+/// escape is kept as written.
+fn unescape_parsed(s: &str) -> Cow<'_, str> {
+    unescape(s).unwrap_or(Cow::Borrowed(s))
+}
+
+fn escape_c1_control_codes(s: &str) -> Cow<'_, str> {
+    escape_c1_control_codes_with(s, b'a')
+}
+
+/// Escapes the C1 control codes as `\u0080` to `\u009f`, with `hex_a` being
+/// the digit for ten, which gron writes in uppercase.
+fn escape_c1_control_codes_with(mut s: &str, hex_a: u8) -> Cow<'_, str> {
     // A codepoint `x` between `0x80` and `0x9f` inclusive is in utf8 encoded as
     // `0xc2` followed by `x`.
     let sb = s.as_bytes();
@@ -177,11 +1456,11 @@ fn escape_c1_control_codes<'a>(mut s: &'a str) -> Cow<'a, str> {
 
     let mut ret = String::new();
     while let Some(i) = memchr::memchr(0xc2, s.as_bytes()) {
-        if i + 1 >= s.as_bytes().len() {
-            continue;
-        }
+        // Being a utf8 lead byte, `0xc2` is always followed by one more byte.
         let val = s.as_bytes()[i + 1];
-        if val < 0x80 || val > 0x9f {
+        if !(0x80..=0x9f).contains(&val) {
+            ret.push_str(&s[..i + 2]);
+            s = &s[i + 2..];
             continue;
         }
         ret.push_str(&s[..i]);
@@ -192,7 +1471,7 @@ fn escape_c1_control_codes<'a>(mut s: &'a str) -> Cow<'a, str> {
         ret.push(char::from(if low_nibble < 10 {
             b'0' + low_nibble
         } else {
-            b'a' - 10 + low_nibble
+            hex_a - 10 + low_nibble
         }));
         s = &s[i + 2..];
     }
@@ -0,0 +1,338 @@
+//! Gronning while reading the input, for inputs too large to hold in memory.
+//! Memory use is bounded by the nesting depth and the longest single string.
+//! Object keys are emitted in document order, since sorting them would require
+//! holding the whole object.
+
+use crate::gron::{is_json_number, Error, Gron, Locals};
+use patched_simd_json::StaticNode;
+use std::io::{self, BufRead, BufReader, BufWriter, Read};
+
+pub(crate) fn process(input: impl Read, gron: Gron, output: impl io::Write) -> Result<(), Error> {
+    let mut parser = Parser {
+        input: BufReader::with_capacity(1 << 16, input),
+        offset: 0,
+        scratch: Vec::new(),
+    };
+    let mut locals = Locals::new(gron, BufWriter::new(output));
+    let result = if gron.colored() {
+        parser.document::<true>(&mut locals)
+    } else {
+        parser.document::<false>(&mut locals)
+    };
+    locals.flush()?;
+    result
+}
+
+/// An object or array being read, with the index of the current element of
+/// an array.
+enum Container {
+    Object,
+    Array(usize),
+}
+
+struct Parser<R> {
+    input: BufReader<R>,
+    offset: usize,
+    /// The current string or number, with strings escaped the same way as by
+    /// the patched simd-json.
+    scratch: Vec<u8>,
+}
+impl<R: Read> Parser<R> {
+    fn error<T>(&self, expected: &'static str) -> Result<T, Error> {
+        Err(Error::Syntax {
+            offset: self.offset,
+            expected,
+        })
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.input.consume(n);
+        self.offset += n;
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, Error> {
+        Ok(self.input.fill_buf()?.first().copied())
+    }
+
+    fn next(&mut self, expected: &'static str) -> Result<u8, Error> {
+        match self.peek()? {
+            Some(byte) => {
+                self.consume(1);
+                Ok(byte)
+            }
+            None => self.error(expected),
+        }
+    }
+
+    /// Skips whitespace and peeks at the byte after it.
+    fn peek_token(&mut self) -> Result<Option<u8>, Error> {
+        loop {
+            let buf = self.input.fill_buf()?;
+            let Some(i) = buf
+                .iter()
+                .position(|b| !matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+            else {
+                if buf.is_empty() {
+                    return Ok(None);
+                }
+                let n = buf.len();
+                self.consume(n);
+                continue;
+            };
+            let byte = buf[i];
+            self.consume(i);
+            return Ok(Some(byte));
+        }
+    }
+
+    fn expect_token(&mut self, token: u8, expected: &'static str) -> Result<(), Error> {
+        if self.peek_token()? == Some(token) {
+            self.consume(1);
+            Ok(())
+        } else {
+            self.error(expected)
+        }
+    }
+
+    fn document<const COLOR: bool>(
+        &mut self,
+        locals: &mut Locals<impl io::Write>,
+    ) -> Result<(), Error> {
+        // The containers around the current value, kept here rather than on
+        // the call stack so that deep nesting cannot overflow it.
+        let mut stack = Vec::new();
+        'value: loop {
+            if self.value::<COLOR>(locals, &mut stack)? {
+                continue;
+            }
+            // Close the containers that the value ends, up to one that has
+            // another element.
+            loop {
+                let Some(container) = stack.last_mut() else {
+                    return match self.peek_token()? {
+                        None => Ok(()),
+                        Some(_) => self.error("end of input"),
+                    };
+                };
+                locals.pop();
+                match (self.peek_token()?, container) {
+                    (Some(b','), Container::Object) => {
+                        self.consume(1);
+                        self.key::<COLOR>(locals)?;
+                        continue 'value;
+                    }
+                    (Some(b','), Container::Array(i)) => {
+                        self.consume(1);
+                        *i += 1;
+                        locals.push_index::<COLOR>(*i);
+                        continue 'value;
+                    }
+                    (Some(b'}'), Container::Object) | (Some(b']'), Container::Array(_)) => {
+                        self.consume(1);
+                        stack.pop();
+                    }
+                    (_, Container::Object) => return self.error("`,` or `}`"),
+                    (_, Container::Array(_)) => return self.error("`,` or `]`"),
+                }
+            }
+        }
+    }
+
+    /// Reads a value, or the start of an object or array with elements, which
+    /// is then entered up to its first element. Returns whether it was entered.
+    fn value<const COLOR: bool>(
+        &mut self,
+        locals: &mut Locals<impl io::Write>,
+        stack: &mut Vec<Container>,
+    ) -> Result<bool, Error> {
+        match self.peek_token()? {
+            Some(b'{') => {
+                self.consume(1);
+                locals.write_empty_object::<COLOR>()?;
+                if self.peek_token()? == Some(b'}') {
+                    self.consume(1);
+                    return Ok(false);
+                }
+                stack.push(Container::Object);
+                self.key::<COLOR>(locals)?;
+                Ok(true)
+            }
+            Some(b'[') => {
+                self.consume(1);
+                locals.write_empty_array::<COLOR>()?;
+                if self.peek_token()? == Some(b']') {
+                    self.consume(1);
+                    return Ok(false);
+                }
+                stack.push(Container::Array(0));
+                locals.push_index::<COLOR>(0);
+                Ok(true)
+            }
+            Some(b'"') => {
+                self.consume(1);
+                self.string()?;
+                locals.write_string::<COLOR>(self.scratch_str()?)?;
+                Ok(false)
+            }
+            Some(b't') => {
+                self.literal(b"true")?;
+                locals.write_static::<COLOR>(&StaticNode::Bool(true))?;
+                Ok(false)
+            }
+            Some(b'f') => {
+                self.literal(b"false")?;
+                locals.write_static::<COLOR>(&StaticNode::Bool(false))?;
+                Ok(false)
+            }
+            Some(b'n') => {
+                self.literal(b"null")?;
+                locals.write_static::<COLOR>(&StaticNode::Null)?;
+                Ok(false)
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                self.number()?;
+                if !locals.write_number::<COLOR>(self.scratch_str()?)? {
+                    return Err(Error::Syntax {
+                        offset: self.offset - self.scratch.len(),
+                        expected: "a number that fits in 64 bits",
+                    });
+                }
+                Ok(false)
+            }
+            _ => self.error("a value"),
+        }
+    }
+
+    /// Reads an object key and the colon after it, and enters the key.
+    fn key<const COLOR: bool>(&mut self, locals: &mut Locals<impl io::Write>) -> Result<(), Error> {
+        self.expect_token(b'"', "`\"`")?;
+        self.string()?;
+        locals.push_key::<COLOR>(self.scratch_str()?);
+        self.expect_token(b':', "`:`")
+    }
+
+    fn scratch_str(&self) -> Result<&str, Error> {
+        std::str::from_utf8(&self.scratch).map_err(|err| Error::Syntax {
+            offset: self.offset - self.scratch.len() + err.valid_up_to(),
+            expected: "valid utf8",
+        })
+    }
+
+    fn literal(&mut self, literal: &'static [u8]) -> Result<(), Error> {
+        for &expected in literal {
+            if self.next("a literal")? != expected {
+                return self.error("`true`, `false` or `null`");
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the rest of a string whose opening quote has been consumed.
+    fn string(&mut self) -> Result<(), Error> {
+        self.scratch.clear();
+        loop {
+            let buf = self.input.fill_buf()?;
+            if buf.is_empty() {
+                return self.error("`\"`");
+            }
+            let end = memchr::memchr2(b'"', b'\\', buf);
+            if let Some(i) = buf[..end.unwrap_or(buf.len())]
+                .iter()
+                .position(|&b| b < 0x20)
+            {
+                self.consume(i);
+                return self.error("an escaped control character");
+            }
+            let Some(i) = end else {
+                self.scratch.extend_from_slice(buf);
+                let n = buf.len();
+                self.consume(n);
+                continue;
+            };
+            let quote = buf[i] == b'"';
+            self.scratch.extend_from_slice(&buf[..i]);
+            self.consume(i + 1);
+            if quote {
+                return Ok(());
+            }
+            match self.next("an escape")? {
+                b'u' => {
+                    let cp = self.unicode_escape()?;
+                    push_codepoint(&mut self.scratch, cp);
+                }
+                escape @ (b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {
+                    self.scratch.extend_from_slice(&[b'\\', escape]);
+                }
+                _ => return self.error("an escape"),
+            }
+        }
+    }
+
+    /// Reads the rest of a `\u` escape, and of a second one if this is a high
+    /// surrogate.
+    fn unicode_escape(&mut self) -> Result<u32, Error> {
+        let cp = self.hex4()?;
+        match cp {
+            0xd800..=0xdbff => {
+                if self.next("a low surrogate")? != b'\\' || self.next("a low surrogate")? != b'u' {
+                    return self.error("a low surrogate");
+                }
+                let low = self.hex4()?;
+                if !(0xdc00..=0xdfff).contains(&low) {
+                    return self.error("a low surrogate");
+                }
+                Ok(0x10000 + ((cp - 0xd800) << 10) + (low - 0xdc00))
+            }
+            0xdc00..=0xdfff => self.error("a high surrogate"),
+            _ => Ok(cp),
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let mut cp = 0;
+        for _ in 0..4 {
+            let digit = char::from(self.next("a hex digit")?)
+                .to_digit(16)
+                .map_or_else(|| self.error("a hex digit"), Ok)?;
+            cp = cp << 4 | digit;
+        }
+        Ok(cp)
+    }
+
+    /// Reads a number into `scratch`, as written.
+    fn number(&mut self) -> Result<(), Error> {
+        self.scratch.clear();
+        while let Some(byte @ (b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) = self.peek()? {
+            self.scratch.push(byte);
+            self.consume(1);
+        }
+        if !is_json_number(&self.scratch) {
+            return self.error("a number");
+        }
+        Ok(())
+    }
+}
+
+/// Appends the codepoint of a `\u` escape the way the patched simd-json does,
+/// keeping it escaped if it is a control character.
+fn push_codepoint(out: &mut Vec<u8>, cp: u32) {
+    match cp {
+        0x22 => out.extend_from_slice(b"\\\""),
+        0x5c => out.extend_from_slice(b"\\\\"),
+        0x08 => out.extend_from_slice(b"\\b"),
+        0x0c => out.extend_from_slice(b"\\f"),
+        0x0a => out.extend_from_slice(b"\\n"),
+        0x0d => out.extend_from_slice(b"\\r"),
+        0x09 => out.extend_from_slice(b"\\t"),
+        0x00..=0x1f | 0x7f..=0x9f => {
+            const HEX: &[u8; 16] = b"0123456789ABCDEF";
+            out.extend_from_slice(b"\\u00");
+            out.push(HEX[cp as usize >> 4]);
+            out.push(HEX[cp as usize & 0xf]);
+        }
+        _ => {
+            let c = char::from_u32(cp).unwrap();
+            out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+    }
+}
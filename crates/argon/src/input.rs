@@ -0,0 +1,98 @@
+//! Reading whole inputs into memory. Regular files are mapped rather than
+//! read, so that their contents are not held twice while parsing, and pipes
+//! are read into a buffer sized by what is known of their length up front.
+
+use memmap2::{MmapMut, MmapOptions};
+use std::{
+    fs,
+    io::{self, Read},
+    ops::{Deref, DerefMut},
+    os::fd::{AsFd, AsRawFd},
+};
+
+/// What a pipe holds at once, which `read_to_end` would otherwise reach by
+/// doubling from a few bytes.
+const MIN_CAPACITY: usize = 1 << 16;
+
+/// A whole input, which the parser uses as scratch space.
+pub enum Buffer {
+    Read(Vec<u8>),
+    /// A private mapping of a regular file. The pages the parser writes to
+    /// are copied rather than written back.
+    Mapped(MmapMut),
+}
+impl Buffer {
+    pub fn is_mapped(&self) -> bool {
+        matches!(self, Buffer::Mapped(_))
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        match self {
+            Buffer::Read(buf) => buf,
+            Buffer::Mapped(mmap) => mmap.to_vec(),
+        }
+    }
+}
+impl From<Vec<u8>> for Buffer {
+    fn from(buf: Vec<u8>) -> Self {
+        Buffer::Read(buf)
+    }
+}
+impl Deref for Buffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Buffer::Read(buf) => buf,
+            Buffer::Mapped(mmap) => mmap,
+        }
+    }
+}
+impl DerefMut for Buffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match self {
+            Buffer::Read(buf) => buf,
+            Buffer::Mapped(mmap) => mmap,
+        }
+    }
+}
+impl AsMut<[u8]> for Buffer {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+/// Maps `file` if it is a regular file, and otherwise reads it.
+pub fn from_file(file: fs::File) -> io::Result<Buffer> {
+    let metadata = file.metadata()?;
+    // Empty files cannot be mapped.
+    if metadata.is_file() && metadata.len() > 0 {
+        // SAFETY: The mapping is private, so writes to it stay in this
+        // process. Should another process truncate the file meanwhile,
+        // reading the missing pages is a SIGBUS, as for any mapped input.
+        let mmap = unsafe { MmapOptions::new().map_copy(&file)? };
+        return Ok(Buffer::Mapped(mmap));
+    }
+    let hint = pipe_capacity(&file).unwrap_or(0);
+    read(file, hint).map(Buffer::Read)
+}
+
+/// Like [`from_file`] for stdin, which may be redirected from a file.
+pub fn from_stdin() -> io::Result<Buffer> {
+    let stdin = io::stdin().as_fd().try_clone_to_owned()?;
+    from_file(fs::File::from(stdin))
+}
+
+/// Reads all of `input`, of which about `hint` bytes are expected.
+pub fn read(mut input: impl Read, hint: usize) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::with_capacity(hint.max(MIN_CAPACITY));
+    input.read_to_end(&mut buf)?;
+    Ok(buf)
+}
+
+/// The size of the pipe buffer, if `file` is a pipe.
+fn pipe_capacity(file: &fs::File) -> Option<usize> {
+    // SAFETY: `F_GETPIPE_SZ` only reads the size of the pipe.
+    let capacity = unsafe { libc::fcntl(file.as_raw_fd(), libc::F_GETPIPE_SZ) };
+    usize::try_from(capacity).ok()
+}
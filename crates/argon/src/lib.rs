@@ -0,0 +1,20 @@
+//! Fast greppable json. [`Gron`] converts json into assignment statements,
+//! one per line, and [`Ungron`] converts them back. [`Diff`] writes the lines
+//! that differ between two documents.
+
+pub mod diff;
+pub mod format;
+pub mod gron;
+mod incremental;
+pub mod select;
+pub mod ungron;
+
+#[cfg(test)]
+mod test;
+
+pub use diff::Diff;
+pub use gron::Gron;
+pub use ungron::Ungron;
+
+/// The json parser, patched to keep strings in their escaped form.
+pub use patched_simd_json as simd_json;
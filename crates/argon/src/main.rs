@@ -1,43 +1,220 @@
-#[cfg(not(target_feature = "avx2"))]
-compile_error!("unexpectedly missing required feature AVX2");
-
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
+use ignore::{overrides::OverrideBuilder, WalkBuilder};
 use std::{
     fs,
     io::{self, Read},
     mem::ManuallyDrop,
-    path::Path,
+    path::{Path, PathBuf},
     process::ExitCode,
+    time::Instant,
 };
 use tracing_subscriber::{filter::targets::Targets, layer::Layer};
 use url::Url;
 
-mod gron;
+use argon::{
+    format::Format,
+    gron::{GrepTarget, LineFormat, PathSyntax},
+    select, ungron, Diff, Gron, Ungron,
+};
+use regex::Regex;
+
+mod input;
 mod seccomp;
-mod ungron;
 
-#[cfg(test)]
-mod test;
+use input::Buffer;
 
 #[derive(clap::Parser, Debug)]
 #[command(about, verbatim_doc_comment)]
 /// Example invocations:
 /// - `echo '[1,"abc\r\ncba"]' | argon`
 /// - `argon path/to/something.json`
+/// - `argon 'path/to/*.json' other.json`
+/// - `argon diff old.json new.json`
+/// - `echo 'json.a = undefined;' | argon patch in.json`
 /// - `argon https://api.github.com/repos/lokegustafsson/argon/commits?per_page=1`
 struct Args {
-    /// Filesystem path or URL to the json file to process.
-    path_or_url_to_json: Option<String>,
-    #[arg(short, long)]
+    /// Filesystem paths, globs or URLs of the json files to process. Several
+    /// files are gronned as one object keyed by their names, like
+    /// `json["a.json"].foo = 1;`.
+    paths_or_urls_to_json: Vec<String>,
+    /// Log more, such as the size of the input and how quickly it was
+    /// processed.
+    #[arg(short, long, global = true)]
     verbose: bool,
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     color: bool,
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     no_color: bool,
     #[arg(short, long)]
     ungron: bool,
+    /// Write keys that are not valid Javascript identifiers as `json["key"]`
+    /// rather than `json.key`, like gron does.
+    #[arg(long, global = true)]
+    bracket_keys: bool,
+    /// Write exactly what gron would: the keys gron does not consider
+    /// identifiers as `json["key"]`, strings escaped like gron and keys
+    /// sorted like gron.
+    #[arg(
+        long,
+        conflicts_with_all = ["ungron", "values", "line_format", "path_syntax", "preserve_order"]
+    )]
+    gron_compat: bool,
+    /// Keep numbers exactly as written, from the input to the gron lines and
+    /// from the lines back to json, rather than as 64-bit integers or floats.
+    #[arg(long, global = true)]
+    lossless_numbers: bool,
+    /// When ungronning, drop array elements that no line assigns to instead of
+    /// filling them with null.
+    #[arg(long, global = true)]
+    compact_arrays: bool,
+    /// When ungronning, skip and report invalid lines instead of failing.
+    #[arg(long)]
+    lenient: bool,
+    /// Gron while reading the input, using bounded memory. Object keys are
+    /// emitted in document order rather than sorted.
+    #[arg(long, conflicts_with = "ungron")]
+    incremental: bool,
+    /// Treat the input as newline-delimited json, one document per line. When
+    /// ungronning, write the elements of the top-level array one per line.
+    #[arg(long, visible_alias = "jsonl", conflicts_with = "incremental")]
+    stream: bool,
+    /// Write only the scalar values, one per line, with strings unquoted.
+    #[arg(long, conflicts_with = "ungron")]
+    values: bool,
+    /// With `--values`, also unescape the strings.
+    #[arg(long, requires = "values")]
+    unescape: bool,
+    /// Write assignments (`gron`), records of the path, type and value
+    /// separated by tabs (`tsv`) or commas (`csv`), or json arrays of the path
+    /// and value (`json`). `--ungron` reads all but csv.
+    #[arg(
+        long,
+        default_value = "gron",
+        value_name = "FORMAT",
+        conflicts_with_all = ["ungron", "values"]
+    )]
+    line_format: LineFormat,
+    /// Write paths like `json.a[0]` (`gron`), as JSON Pointers like `/a/0`
+    /// (`pointer`) or as normalized JSONPaths like `$['a'][0]` (`jsonpath`).
+    /// `--ungron` reads all of them.
+    #[arg(
+        long,
+        default_value = "gron",
+        value_name = "SYNTAX",
+        conflicts_with = "ungron"
+    )]
+    path_syntax: PathSyntax,
+    /// When ungronning, write the json on a single line.
+    #[arg(long, conflicts_with = "indent", global = true)]
+    compact: bool,
+    /// When ungronning, indent the json by this many spaces, or by `tab`.
+    #[arg(long, default_value = "2", global = true)]
+    indent: ungron::Indent,
+    /// When ungronning, write object keys in sorted order rather than in the
+    /// order they first appear.
+    #[arg(long, global = true)]
+    sort_keys: bool,
+    /// Write object keys in document order rather than sorted.
+    #[arg(long, conflicts_with = "ungron")]
+    preserve_order: bool,
+    /// Gron only the values at paths matching this pattern, like
+    /// `json.users[*].email` or `json.**.id`. May be given several times.
+    #[arg(long, conflicts_with = "ungron", value_name = "PATTERN")]
+    select: Vec<select::Pattern>,
+    /// Write only the lines matching this regex, as they would be written
+    /// without color.
+    #[arg(
+        long,
+        conflicts_with = "ungron",
+        value_name = "REGEX",
+        group = "grep_regex"
+    )]
+    grep: Option<Regex>,
+    /// Write only the lines whose path matches this regex.
+    #[arg(
+        long,
+        conflicts_with = "ungron",
+        value_name = "REGEX",
+        group = "grep_regex"
+    )]
+    grep_path: Option<Regex>,
+    /// Write only the lines whose value matches this regex. Strings are
+    /// matched unquoted.
+    #[arg(
+        long,
+        conflicts_with = "ungron",
+        value_name = "REGEX",
+        group = "grep_regex"
+    )]
+    grep_value: Option<Regex>,
+    /// With `--grep`, `--grep-path` or `--grep-value`, write the lines that do
+    /// not match instead.
+    #[arg(long, requires = "grep_regex")]
+    invert_match: bool,
+    /// With `--select` or a grep, also write the objects and arrays containing
+    /// the written lines, so that the output ungrons into values of the same
+    /// types.
+    #[arg(long, conflicts_with = "ungron")]
+    ancestors: bool,
+    /// When ungronning, how to settle lines that assign different values to
+    /// the same path: `error`, `first-wins`, `last-wins` or `merge-to-array`.
+    #[arg(long, default_value = "error", value_name = "POLICY")]
+    on_conflict: ungron::Conflict,
+    /// Gron every file matching `--include` within directories given as
+    /// inputs, skipping files ignored by `.gitignore` or `.ignore` files.
+    /// The files are named by their path relative to the directory.
+    #[arg(short, long)]
+    recursive: bool,
+    /// With `--recursive`, the glob that files must match, in `.gitignore`
+    /// syntax.
+    #[arg(
+        long,
+        default_value = "*.json",
+        value_name = "GLOB",
+        requires = "recursive"
+    )]
+    include: String,
+    /// When ungronning, write each top-level key to its own file in this
+    /// directory, undoing the gronning of several files.
+    #[arg(long, requires = "ungron", value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+    /// The format of the documents to gron, `json`, `yaml` or `toml`. By
+    /// default, it is chosen by the file extension, falling back to json.
+    #[arg(long, global = true, value_name = "FORMAT")]
+    input_format: Option<Format>,
+    /// The format to write when ungronning, `json`, `yaml` or `toml`.
+    #[arg(long, global = true, default_value = "json", value_name = "FORMAT")]
+    output_format: Format,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Write the gron lines that differ between two json files or URLs, those
+    /// only in `OLD` prefixed by `-` and those only in `NEW` by `+`.
+    Diff {
+        old: String,
+        new: String,
+        /// Compare arrays ignoring the order of their elements.
+        #[arg(long)]
+        ignore_array_order: bool,
+    },
+    /// Apply gron lines to a json file or URL and write the result. Later
+    /// lines replace earlier values, and `json.a = undefined;` deletes
+    /// `json.a`.
+    ///
+    /// Assigning `{}` or `[]` to an object or array keeps what it holds, so
+    /// that gron output patches only the values it assigns. Delete the
+    /// container first to empty it.
+    #[command(visible_alias = "apply")]
+    Patch {
+        json: String,
+        /// The lines to apply, read from stdin if not given.
+        lines: Option<PathBuf>,
+    },
 }
 
 fn main() -> ExitCode {
@@ -50,37 +227,352 @@ fn main_impl() -> Result<(), ()> {
     let args: Args = clap::Parser::parse();
     setup_logging(args.verbose);
 
-    let mut buf = if let Some(path_or_url_to_json) = &args.path_or_url_to_json {
-        if let Ok(url_to_json) = Url::parse(path_or_url_to_json) {
-            from_url(url_to_json)?
-        } else {
-            from_file(Path::new(path_or_url_to_json), args.ungron)?
+    match &args.command {
+        Some(Command::Diff {
+            old,
+            new,
+            ignore_array_order,
+        }) => return diff(&args, old, new, *ignore_array_order),
+        Some(Command::Patch { json, lines }) => return patch(&args, json, lines.as_deref()),
+        None => {}
+    }
+
+    let (inputs, named) = inputs(&args)?;
+    if (args.incremental || args.stream) && (named || inputs.len() > 1) {
+        tracing::error!("--incremental and --stream take a single input");
+        return Err(());
+    }
+    if (args.ungron || args.incremental || args.stream)
+        && args
+            .input_format
+            .is_some_and(|format| format != Format::Json)
+    {
+        tracing::error!("--ungron, --incremental and --stream only read json");
+        return Err(());
+    }
+
+    if args.incremental {
+        let start = Instant::now();
+        let input: Box<dyn Read> = match inputs.into_iter().next().map(|(_, input)| input) {
+            Some(Input::Url(url_to_json)) => Box::new(request(url_to_json)?),
+            Some(Input::File(path)) => Box::new(open_file(&path, args.ungron)?),
+            None => Box::new(io::stdin().lock()),
+        };
+        let mut input = Counted {
+            inner: input,
+            bytes: 0,
+        };
+
+        seccomp::setup_seccomp(args.ungron, args.incremental, false);
+
+        let result = gron(&args)
+            .reader_to_writer(&mut input, io::stdout().lock())
+            .map_err(|err| tracing::error!(%err, "could not gron"));
+        if args.verbose {
+            // The input is read as it is processed, so both are logged at
+            // the end.
+            let elapsed = start.elapsed();
+            let throughput = input.bytes as f64 / elapsed.as_secs_f64() / 1e6;
+            tracing::info!(bytes = input.bytes, mapped = false, ?elapsed, "read input");
+            tracing::info!(
+                bytes = input.bytes,
+                ?elapsed,
+                throughput = format!("{throughput:.1} MB/s"),
+                "processed input"
+            );
         }
-    } else {
-        let mut buf = Vec::new();
-        io::stdin().lock().read_to_end(&mut buf).unwrap();
+        return result;
+    }
+
+    let start = Instant::now();
+    let convert = !args.ungron && !args.stream;
+    let mut documents = Vec::with_capacity(inputs.len());
+    for (name, input) in inputs {
+        let (path, buf) = match input {
+            Input::Url(url_to_json) => (None, from_url(url_to_json)?),
+            Input::File(path) => {
+                let buf = from_file(&path, args.ungron)?;
+                (Some(path), buf)
+            }
+        };
+        let buf = if convert {
+            to_json(&args, path.as_deref(), buf)?
+        } else {
+            buf
+        };
+        documents.push((name, buf));
+    }
+    let mut buf = if named {
+        Buffer::from(Vec::new())
+    } else if let Some((_, buf)) = documents.pop() {
         buf
+    } else {
+        let buf =
+            input::from_stdin().map_err(|err| tracing::error!(?err, "could not read stdin"))?;
+        if convert {
+            to_json(&args, None, buf)?
+        } else {
+            buf
+        }
     };
+    let input_len = buf.len() + documents.iter().map(|(_, buf)| buf.len()).sum::<usize>();
+    if args.verbose {
+        let mapped = buf.is_mapped() || documents.iter().any(|(_, buf)| buf.is_mapped());
+        tracing::info!(
+            bytes = input_len,
+            mapped,
+            elapsed = ?start.elapsed(),
+            "read input"
+        );
+    }
+    if named && args.ungron {
+        // The lines of several files are ungronned together.
+        let mut all_lines = Vec::with_capacity(input_len + documents.len());
+        for (_, lines) in documents.drain(..) {
+            all_lines.extend_from_slice(&lines);
+            if !lines.ends_with(b"\n") {
+                all_lines.push(b'\n');
+            }
+        }
+        buf = Buffer::from(all_lines);
+    }
 
-    seccomp::setup_seccomp(args.ungron);
+    seccomp::setup_seccomp(
+        args.ungron || args.stream || named,
+        args.incremental,
+        args.output_dir.is_some(),
+    );
 
-    let output = Box::new(io::stdout().lock());
+    let output = io::stdout().lock();
 
     if args.ungron {
-        ungron::process(&buf, output)?;
+        let ungron = ungron(&args)
+            .lenient(args.lenient)
+            .jsonl(args.stream)
+            .on_conflict(args.on_conflict);
+        let json = ungron
+            .to_value(&buf)
+            .map_err(|err| tracing::error!(%err, "could not ungron"))?;
+        if let Some(output_dir) = &args.output_dir {
+            ungron.value_to_dir(&json, output_dir)
+        } else {
+            ungron.value_to_writer(&json, output)
+        }
+        .map_err(|err| tracing::error!(%err, "could not ungron"))?;
+        // Leak `json` for quicker exit
+        let _ = ManuallyDrop::new(json);
+    } else if named {
+        gron(&args)
+            .named_to_writer(&mut documents, output)
+            .map_err(|err| tracing::error!(%err, "could not gron"))?;
+        // Leak `documents` for quicker exit
+        let _ = ManuallyDrop::new(documents);
+    } else if args.stream {
+        gron(&args)
+            .lines_to_writer(&mut buf, output)
+            .map_err(|err| tracing::error!(%err, "could not gron"))?;
     } else {
-        let have_color = match (args.color, args.no_color, atty::is(atty::Stream::Stdout)) {
-            (true, false, _) => true,
-            (false, true, _) => false,
-            (_, _, tty) => tty,
-        };
-        gron::process(&mut buf, have_color, output)?;
+        let mut quoted = Vec::new();
+        let json = gron(&args)
+            .parse(&mut buf, &mut quoted)
+            .map_err(|err| tracing::error!(?err, "could not parse json"))?;
+        gron(&args)
+            .value_to_writer(&json, output)
+            .map_err(|err| tracing::error!(%err, "could not gron"))?;
+        // Leak `json` and `quoted` for quicker exit
+        let _ = ManuallyDrop::new(json);
+        let _ = ManuallyDrop::new(quoted);
+    }
+    if args.verbose {
+        let elapsed = start.elapsed();
+        let throughput = input_len as f64 / elapsed.as_secs_f64() / 1e6;
+        tracing::info!(
+            bytes = input_len,
+            ?elapsed,
+            throughput = format!("{throughput:.1} MB/s"),
+            "processed input"
+        );
     }
     // Leak `buf` for quicker exit
     let _ = ManuallyDrop::new(buf);
     Ok(())
 }
 
+fn diff(args: &Args, old: &str, new: &str, ignore_array_order: bool) -> Result<(), ()> {
+    let mut old_buf = to_json(args, Some(Path::new(old)), from_path_or_url(old)?)?;
+    let mut new_buf = to_json(args, Some(Path::new(new)), from_path_or_url(new)?)?;
+
+    seccomp::setup_seccomp(false, false, false);
+
+    let (mut old_quoted, mut new_quoted) = (Vec::new(), Vec::new());
+    let old_json = gron(args)
+        .parse(&mut old_buf, &mut old_quoted)
+        .map_err(|err| tracing::error!(?err, path = old, "could not parse json"))?;
+    let new_json = gron(args)
+        .parse(&mut new_buf, &mut new_quoted)
+        .map_err(|err| tracing::error!(?err, path = new, "could not parse json"))?;
+    Diff::new()
+        .color(have_color(args))
+        .bracket_keys(args.bracket_keys)
+        .ignore_array_order(ignore_array_order)
+        .lossless_numbers(args.lossless_numbers)
+        .values_to_writer(&old_json, &new_json, io::stdout().lock())
+        .map_err(|err| tracing::error!(%err, "could not diff"))?;
+    // Leak everything for quicker exit
+    let _ = ManuallyDrop::new((old_json, new_json));
+    let _ = ManuallyDrop::new((old_buf, new_buf, old_quoted, new_quoted));
+    Ok(())
+}
+
+fn patch(args: &Args, json: &str, lines: Option<&Path>) -> Result<(), ()> {
+    let json = to_json(args, Some(Path::new(json)), from_path_or_url(json)?)?;
+    let lines = match lines {
+        Some(path) => fs::File::open(path).and_then(input::from_file),
+        None => input::from_stdin(),
+    }
+    .map_err(|err| tracing::error!(?err, "could not read lines"))?;
+
+    seccomp::setup_seccomp(false, false, false);
+
+    ungron(args)
+        .patch_to_writer(&json, &lines, io::stdout().lock())
+        .map_err(|err| tracing::error!(%err, "could not patch"))
+}
+
+/// Converts a document to json, as given by `--input-format` or else by the
+/// extension of its path.
+fn to_json(args: &Args, path: Option<&Path>, buf: Buffer) -> Result<Buffer, ()> {
+    let format = args
+        .input_format
+        .or_else(|| path.and_then(Format::from_path))
+        .unwrap_or_default();
+    if format == Format::Json {
+        return Ok(buf);
+    }
+    format
+        .to_json(buf.into_vec())
+        .map(Buffer::from)
+        .map_err(|err| tracing::error!(%err, "could not convert to json"))
+}
+
+enum Input {
+    Url(Url),
+    File(PathBuf),
+}
+
+/// Expands globs and, with `--recursive`, directories among the arguments, and
+/// names each input. The inputs are gronned under their names if there are
+/// several or a glob or directory was given.
+fn inputs(args: &Args) -> Result<(Vec<(String, Input)>, bool), ()> {
+    let mut inputs = Vec::new();
+    let mut named = args.paths_or_urls_to_json.len() > 1;
+    for path_or_url in &args.paths_or_urls_to_json {
+        if let Ok(url) = Url::parse(path_or_url) {
+            inputs.push((url.to_string(), Input::Url(url)));
+            continue;
+        }
+        let path = Path::new(path_or_url);
+        if args.recursive && path.is_dir() {
+            named = true;
+            walk(path, &args.include, &mut inputs)?;
+            continue;
+        }
+        if path.exists() || !path_or_url.contains(['*', '?', '[']) {
+            inputs.push((path_or_url.clone(), Input::File(path.to_owned())));
+            continue;
+        }
+        named = true;
+        let paths = glob::glob(path_or_url)
+            .map_err(|err| tracing::error!(%err, pattern = path_or_url, "invalid glob"))?;
+        let len = inputs.len();
+        for path in paths {
+            let path = path.map_err(|err| tracing::error!(%err, "could not expand glob"))?;
+            inputs.push((path.display().to_string(), Input::File(path)));
+        }
+        if inputs.len() == len {
+            tracing::error!(pattern = path_or_url, "no files match the glob");
+            return Err(());
+        }
+    }
+    Ok((inputs, named))
+}
+
+/// Adds the files within `dir` that match `include` and are not ignored by
+/// `.gitignore` or `.ignore` files, named by their path relative to `dir`.
+fn walk(dir: &Path, include: &str, inputs: &mut Vec<(String, Input)>) -> Result<(), ()> {
+    let overrides = OverrideBuilder::new(dir)
+        .add(include)
+        .and_then(|overrides| overrides.build())
+        .map_err(|err| tracing::error!(%err, glob = include, "invalid glob"))?;
+    let walk = WalkBuilder::new(dir)
+        .overrides(overrides)
+        .require_git(false)
+        .sort_by_file_name(Ord::cmp)
+        .build();
+    for entry in walk {
+        let entry = entry.map_err(|err| tracing::error!(%err, "could not walk directory"))?;
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        let name = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+        inputs.push((
+            name.display().to_string(),
+            Input::File(entry.path().to_owned()),
+        ));
+    }
+    Ok(())
+}
+
+fn have_color(args: &Args) -> bool {
+    match (args.color, args.no_color, atty::is(atty::Stream::Stdout)) {
+        (true, false, _) => true,
+        (false, true, _) => false,
+        (_, _, tty) => tty,
+    }
+}
+
+fn gron(args: &Args) -> Gron<'_> {
+    Gron::new()
+        .color(have_color(args))
+        .bracket_keys(args.bracket_keys)
+        .values(args.values)
+        .unescape(args.unescape)
+        .preserve_order(args.preserve_order)
+        .select(&args.select)
+        .grep(
+            args.grep
+                .as_ref()
+                .or(args.grep_path.as_ref())
+                .or(args.grep_value.as_ref()),
+        )
+        .grep_target(if args.grep_path.is_some() {
+            GrepTarget::Path
+        } else if args.grep_value.is_some() {
+            GrepTarget::Value
+        } else {
+            GrepTarget::Line
+        })
+        .invert_match(args.invert_match)
+        .ancestors(args.ancestors)
+        .line_format(args.line_format)
+        .path_syntax(args.path_syntax)
+        .gron_compat(args.gron_compat)
+        .lossless_numbers(args.lossless_numbers)
+}
+
+fn ungron(args: &Args) -> Ungron {
+    Ungron::new()
+        .indent(if args.compact {
+            ungron::Indent::Compact
+        } else {
+            args.indent
+        })
+        .sort_keys(args.sort_keys)
+        .compact_arrays(args.compact_arrays)
+        .format(args.output_format)
+        .lossless_numbers(args.lossless_numbers)
+}
+
 fn setup_logging(verbose: bool) {
     tracing::subscriber::set_global_default(
         Targets::new()
@@ -120,7 +612,20 @@ fn setup_logging(verbose: bool) {
     }));
 }
 
-fn from_url(url: Url) -> Result<Vec<u8>, ()> {
+fn from_path_or_url(path_or_url: &str) -> Result<Buffer, ()> {
+    match Url::parse(path_or_url) {
+        Ok(url) => from_url(url),
+        Err(_) => from_file(Path::new(path_or_url), false),
+    }
+}
+fn from_url(url: Url) -> Result<Buffer, ()> {
+    let resp = request(url)?;
+    let hint = resp.content_length().unwrap_or(0);
+    input::read(resp, usize::try_from(hint).unwrap_or(0))
+        .map(Buffer::from)
+        .map_err(|err| tracing::error!(?err, "could not read response"))
+}
+fn request(url: Url) -> Result<reqwest::blocking::Response, ()> {
     let resp = reqwest::blocking::Client::builder()
         .user_agent(concat!(
             env!("CARGO_PKG_NAME"),
@@ -134,28 +639,43 @@ fn from_url(url: Url) -> Result<Vec<u8>, ()> {
         .send()
         .map_err(|err| tracing::error!(?err, "making request"))?;
     if resp.status().is_success() {
-        Ok(resp.bytes().unwrap().as_ref().to_owned())
+        Ok(resp)
     } else {
         tracing::error!(status = %resp.status(), body = resp.text().unwrap_or("<missing".to_owned()), "server responded");
         Err(())
     }
 }
-fn from_file(path: &Path, ungron: bool) -> Result<Vec<u8>, ()> {
-    let target = Path::new(path);
-    if let Some(extension) = target.extension() {
-        if !ungron && extension != "json" {
-            tracing::warn!("target missing json file extension; proceeding anyway");
-        }
-    } else {
-        tracing::error!("cannot process a directory");
-        return Err(());
+fn from_file(path: &Path, ungron: bool) -> Result<Buffer, ()> {
+    check_extension(path, ungron)?;
+    fs::File::open(path)
+        .and_then(input::from_file)
+        .map_err(|err| tracing::error!(?err, "could not read file"))
+}
+fn open_file(path: &Path, ungron: bool) -> Result<fs::File, ()> {
+    check_extension(path, ungron)?;
+    fs::File::open(path).map_err(|err| tracing::error!(?err, "could not open file"))
+}
+/// A reader that counts the bytes read through it, for `--verbose` with
+/// `--incremental`.
+struct Counted<R> {
+    inner: R,
+    bytes: usize,
+}
+impl<R: Read> Read for Counted<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.bytes += read;
+        Ok(read)
     }
+}
 
-    match fs::read(target) {
-        Ok(buf) => Ok(buf),
-        Err(err) => {
-            tracing::error!(?err, "could not read file");
-            Err(())
-        }
+fn check_extension(target: &Path, ungron: bool) -> Result<(), ()> {
+    if target.is_dir() {
+        tracing::error!("cannot process a directory without --recursive");
+        return Err(());
     }
+    if !ungron && Format::from_path(target).is_none() {
+        tracing::warn!("target missing json, yaml or toml file extension; proceeding anyway");
+    }
+    Ok(())
 }
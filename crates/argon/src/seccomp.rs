@@ -10,7 +10,10 @@ compile_error!("supports only x86_64 and aarch64");
 #[cfg(not(target_os = "linux"))]
 compile_error!("supports only linux");
 
-pub fn setup_seccomp(ungron: bool) {
+/// `multithreaded` allows what rayon's thread pool needs, as used by ungron and
+/// by gron of newline-delimited json or several files. `writes_files` allows
+/// creating the files and directories of `--output-dir`.
+pub fn setup_seccomp(multithreaded: bool, incremental: bool, writes_files: bool) {
     let mut rules = vec![(libc::SYS_write, vec![])];
     rules.extend_from_slice(&[
         (libc::SYS_exit_group, vec![]),
@@ -19,12 +22,35 @@ pub fn setup_seccomp(ungron: bool) {
         (libc::SYS_munmap, vec![]),
         (libc::SYS_sigaltstack, vec![]),
     ]);
-    if ungron {
+    if incremental {
+        // The input is still being read, possibly through the http client's
+        // runtime thread, and is closed when done.
+        rules.extend_from_slice(&[
+            (libc::SYS_close, vec![]),
+            (libc::SYS_fcntl, vec![]),
+            (libc::SYS_futex, vec![]),
+            (libc::SYS_mmap, vec![]),
+            (libc::SYS_read, vec![]),
+        ]);
+    }
+    if writes_files {
+        rules.extend_from_slice(&[
+            (libc::SYS_close, vec![]),
+            (libc::SYS_mkdirat, vec![]),
+            (libc::SYS_openat, vec![]),
+            (libc::SYS_statx, vec![]),
+        ]);
+        #[cfg(target_arch = "x86_64")]
+        rules.push((libc::SYS_mkdir, vec![]));
+    }
+    if multithreaded {
         rules.extend_from_slice(&[
             (libc::SYS_clone3, vec![]),
             (libc::SYS_close, vec![]),
+            (libc::SYS_fcntl, vec![]),
             (libc::SYS_futex, vec![]),
             (libc::SYS_getrandom, vec![]),
+            (libc::SYS_gettid, vec![]),
             (libc::SYS_lseek, vec![]),
             (libc::SYS_mmap, vec![]),
             (libc::SYS_mprotect, vec![]),
@@ -0,0 +1,232 @@
+//! Path patterns selecting which parts of a document to gron, like
+//! `json.users[*].email` or `json.**.id`.
+
+use std::{fmt, str::FromStr};
+
+/// A path pattern for [`Gron::select`](crate::Gron::select). Patterns start
+/// with `json`, followed by any of
+/// - `.key` or `["key"]`, matching an object key. Unquoted keys may contain
+///   `*`, matching any sequence of characters, so `.*` matches any key.
+/// - `[3]` or `[*]`, matching one or any array index.
+/// - `.**`, matching any number of keys and indices, including none.
+///
+/// Quoted keys are written escaped, as in the gron output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    text: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Glob(String),
+    Index(usize),
+    AnyIndex,
+    Descendants,
+}
+
+impl FromStr for Pattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |expected: &str, rest: &str| {
+            let column = s.len() - rest.len() + 1;
+            format!("column {column} of `{s}`: expected {expected}")
+        };
+        let Some(mut rest) = s.strip_prefix("json") else {
+            return Err(error("`json`", s));
+        };
+        let mut segments = Vec::new();
+        while !rest.is_empty() {
+            if let Some(after_dot) = rest.strip_prefix('.') {
+                let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+                let key = &after_dot[..end];
+                segments.push(match key {
+                    "" => return Err(error("a key", after_dot)),
+                    "**" => Segment::Descendants,
+                    _ if key.contains('*') => Segment::Glob(key.to_owned()),
+                    _ => Segment::Key(key.to_owned()),
+                });
+                rest = &after_dot[end..];
+            } else if let Some(after_bracket) = rest.strip_prefix('[') {
+                let (segment, after) = if let Some(after) = after_bracket.strip_prefix('*') {
+                    (Segment::AnyIndex, after)
+                } else if let Some(quoted) = after_bracket.strip_prefix('"') {
+                    let end = string_end(quoted).ok_or_else(|| error("`\"`", ""))?;
+                    (Segment::Key(quoted[..end].to_owned()), &quoted[end + 1..])
+                } else {
+                    let end = after_bracket
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(after_bracket.len());
+                    let index = after_bracket[..end]
+                        .parse()
+                        .map_err(|_| error("an index, `*` or `\"`", after_bracket))?;
+                    (Segment::Index(index), &after_bracket[end..])
+                };
+                rest = after.strip_prefix(']').ok_or_else(|| error("`]`", after))?;
+                segments.push(segment);
+            } else {
+                return Err(error("`.` or `[`", rest));
+            }
+        }
+        Ok(Pattern {
+            text: s.to_owned(),
+            segments,
+        })
+    }
+}
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// The index of the closing quote of an escaped string.
+fn string_end(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => return Some(i),
+            b'\\' => i += 2,
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Whether `text` matches `glob`, where `*` matches any sequence of bytes.
+fn glob_match(glob: &str, text: &str) -> bool {
+    let (glob, text) = (glob.as_bytes(), text.as_bytes());
+    let (mut g, mut t) = (0, 0);
+    // Where to resume after the last `*` if the rest fails to match.
+    let mut backtrack = None;
+    while t < text.len() {
+        if g < glob.len() && glob[g] == b'*' {
+            backtrack = Some((g + 1, t));
+            g += 1;
+        } else if g < glob.len() && glob[g] == text[t] {
+            g += 1;
+            t += 1;
+        } else if let Some((star_g, star_t)) = backtrack {
+            g = star_g;
+            t = star_t + 1;
+            backtrack = Some((star_g, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    glob[g..].iter().all(|&b| b == b'*')
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    /// Some pattern matches an ancestor, so everything below is selected.
+    Matched,
+    /// The path so far matches the first `segment` segments of `pattern`.
+    At { pattern: usize, segment: usize },
+}
+
+/// Tracks which patterns can still match while walking down a document, so
+/// that subtrees no pattern can match are skipped.
+pub(crate) struct Cursor<'a> {
+    patterns: &'a [Pattern],
+    /// The states of every level of the current path, flattened.
+    states: Vec<State>,
+    /// Where the states of each level start in `states`.
+    starts: Vec<usize>,
+}
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(patterns: &'a [Pattern]) -> Self {
+        let mut cursor = Self {
+            patterns,
+            states: Vec::new(),
+            starts: vec![0],
+        };
+        for pattern in 0..patterns.len() {
+            cursor.enter(pattern, 0);
+        }
+        cursor
+    }
+
+    fn top(&self) -> &[State] {
+        &self.states[*self.starts.last().unwrap()..]
+    }
+
+    /// Whether some pattern matches the current path or one of its ancestors.
+    pub(crate) fn is_matched(&self) -> bool {
+        self.top().contains(&State::Matched)
+    }
+
+    /// Whether no pattern can match the current path or anything below it.
+    pub(crate) fn is_pruned(&self) -> bool {
+        self.top().is_empty()
+    }
+
+    pub(crate) fn push_key(&mut self, key: &str) {
+        self.push(|segment| match segment {
+            Segment::Key(k) => k == key,
+            Segment::Glob(glob) => glob_match(glob, key),
+            Segment::Descendants => true,
+            Segment::Index(_) | Segment::AnyIndex => false,
+        });
+    }
+
+    pub(crate) fn push_index(&mut self, i: usize) {
+        self.push(|segment| match segment {
+            Segment::Index(j) => *j == i,
+            Segment::AnyIndex | Segment::Descendants => true,
+            Segment::Key(_) | Segment::Glob(_) => false,
+        });
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.states.truncate(self.starts.pop().unwrap());
+    }
+
+    fn push(&mut self, matches: impl Fn(&Segment) -> bool) {
+        let parent = *self.starts.last().unwrap();
+        let end = self.states.len();
+        self.starts.push(end);
+        if self.states[parent..end].contains(&State::Matched) {
+            self.states.push(State::Matched);
+            return;
+        }
+        for i in parent..end {
+            let State::At { pattern, segment } = self.states[i] else {
+                unreachable!()
+            };
+            let s = &self.patterns[pattern].segments[segment];
+            if matches(s) {
+                // `**` may go on to match more levels.
+                let next = if *s == Segment::Descendants {
+                    segment
+                } else {
+                    segment + 1
+                };
+                self.enter(pattern, next);
+            }
+        }
+    }
+
+    /// Adds a state to the current level, along with the states reached by
+    /// letting `**` match nothing.
+    fn enter(&mut self, pattern: usize, mut segment: usize) {
+        loop {
+            let segments = &self.patterns[pattern].segments;
+            let state = if segment == segments.len() {
+                State::Matched
+            } else {
+                State::At { pattern, segment }
+            };
+            if !self.top().contains(&state) {
+                self.states.push(state);
+            }
+            if segments.get(segment) != Some(&Segment::Descendants) {
+                return;
+            }
+            segment += 1;
+        }
+    }
+}
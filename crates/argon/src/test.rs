@@ -1,22 +1,40 @@
+use crate::{
+    format::{self, Format},
+    gron::{self, GrepTarget, LineFormat, PathSyntax},
+    select,
+    ungron::{self, Conflict, Indent},
+    Diff, Gron, Ungron,
+};
 use include_dir::Dir;
-use std::{cell::RefCell, ffi::OsStr, io, rc::Rc};
+use std::{ffi::OsStr, io};
 
 const TEST_CASES_ROUNDTRIP: Dir<'static> = include_dir::include_dir!("$TEST_CASE_DIR/roundtrip");
 const TEST_CASES_GRON: Dir<'static> = include_dir::include_dir!("$TEST_CASE_DIR/gron");
+const TEST_CASES_GRON_COMPAT: Dir<'static> =
+    include_dir::include_dir!("$TEST_CASE_DIR/gron-compat");
 const TEST_CASES_UNGRON: Dir<'static> = include_dir::include_dir!("$TEST_CASE_DIR/ungron");
+const TEST_CASES_ESCAPES: Dir<'static> = include_dir::include_dir!("$TEST_CASE_DIR/escapes");
 
 const HAVE_COLOR: bool = false;
 
-fn gron(input: &str) -> String {
+fn gron(input: &str, bracket_keys: bool) -> String {
+    gron_with(input, Gron::new().bracket_keys(bracket_keys))
+}
+fn gron_with(input: &str, gron: Gron) -> String {
     let mut input = input.as_bytes().to_owned();
-    let (output, ret) = Output::new();
-    crate::gron::process(&mut input, HAVE_COLOR, output).unwrap();
-    ret.get()
+    let mut output = Vec::new();
+    gron.color(HAVE_COLOR)
+        .to_writer(&mut input, &mut output)
+        .unwrap();
+    String::from_utf8(output).unwrap()
 }
 fn ungron(input: &[u8]) -> String {
-    let (output, ret) = Output::new();
-    crate::ungron::process(input, output).unwrap();
-    ret.get()
+    ungron_with(input, Ungron::new())
+}
+fn ungron_with(input: &[u8], ungron: Ungron) -> String {
+    let mut output = Vec::new();
+    ungron.to_writer(input, &mut output).unwrap();
+    String::from_utf8(output).unwrap()
 }
 
 #[test]
@@ -29,50 +47,440 @@ fn roundtrip_cases() {
         assert_eq!(entry.path().extension(), Some(OsStr::new("json")));
         let sample = entry.contents_utf8().unwrap();
 
-        let lines = gron(&sample);
-        dbg!(&lines);
-        let json = ungron(lines.as_bytes());
-        if &json != &sample {
-            panic!(
-                concat!(
-                    "roundtrip test failure\n",
-                    "BEGIN SAMPLE\n",
-                    "{}\n",
-                    "END SAMPLE\n",
-                    "BEGIN LINES\n",
-                    "{}\n",
-                    "END LINES\n",
-                    "BEGIN JSON\n",
-                    "{}\n",
-                    "END JSON",
-                ),
-                sample, lines, json
-            );
+        for bracket_keys in [false, true] {
+            let lines = gron(sample, bracket_keys);
+            dbg!(&lines);
+            let json = ungron(lines.as_bytes());
+            if json != sample {
+                panic!(
+                    concat!(
+                        "roundtrip test failure\n",
+                        "BEGIN SAMPLE\n",
+                        "{}\n",
+                        "END SAMPLE\n",
+                        "BEGIN LINES\n",
+                        "{}\n",
+                        "END LINES\n",
+                        "BEGIN JSON\n",
+                        "{}\n",
+                        "END JSON",
+                    ),
+                    sample, lines, json
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tsv_roundtrip() {
+    for entry in TEST_CASES_ROUNDTRIP.files() {
+        let sample = entry.contents_utf8().unwrap();
+        let gron = Gron::new().bracket_keys(true).line_format(LineFormat::Tsv);
+        let records = gron_with(sample, gron);
+        assert_eq!(ungron(records.as_bytes()), sample, "{:?}", entry.path());
+    }
+}
+
+#[test]
+fn path_syntax_roundtrip() {
+    for entry in TEST_CASES_ROUNDTRIP.files() {
+        let sample = entry.contents_utf8().unwrap();
+        for path_syntax in [PathSyntax::Pointer, PathSyntax::JsonPath] {
+            for line_format in [LineFormat::Gron, LineFormat::Tsv] {
+                let gron = Gron::new()
+                    .path_syntax(path_syntax)
+                    .line_format(line_format);
+                let lines = gron_with(sample, gron);
+                assert_eq!(
+                    ungron(lines.as_bytes()),
+                    sample,
+                    "{:?} {path_syntax:?}",
+                    entry.path(),
+                );
+            }
         }
     }
 }
 
+#[test]
+fn json_lines_roundtrip() {
+    let tricky = concat!(
+        "{\n",
+        "  \"\": {\n",
+        "    \"a.b[0] = 1;\": [\n",
+        "      \"x\\ny\",\n",
+        "      {}\n",
+        "    ],\n",
+        "    \"0\": {\n",
+        "      \"\\\"]\": null\n",
+        "    }\n",
+        "  }\n",
+        "}\n",
+    );
+    let samples = TEST_CASES_ROUNDTRIP
+        .files()
+        .map(|entry| entry.contents_utf8().unwrap());
+    for sample in samples.chain([tricky]) {
+        let lines = gron_with(
+            sample,
+            Gron::new()
+                .preserve_order(true)
+                .line_format(LineFormat::Json),
+        );
+        assert_eq!(ungron(lines.as_bytes()), sample, "{lines}");
+    }
+}
+
+#[test]
+fn lossless_numbers_roundtrip() {
+    let sample = concat!(
+        "{\n",
+        "  \"id\": 123456789012345678901234567890,\n",
+        "  \"price\": 1.10,\n",
+        "  \"huge\": 1e400,\n",
+        "  \"tiny\": -0.5E-3,\n",
+        "  \"zero\": -0,\n",
+        "  \"string\": \"1.10\",\n",
+        "  \"array\": [\n",
+        "    1E+2,\n",
+        "    18446744073709551616\n",
+        "  ]\n",
+        "}\n",
+    );
+    let ungron = Ungron::new().lossless_numbers(true);
+    for line_format in [LineFormat::Gron, LineFormat::Tsv, LineFormat::Json] {
+        let gron = Gron::new()
+            .preserve_order(true)
+            .lossless_numbers(true)
+            .line_format(line_format);
+        let lines = gron_with(sample, gron);
+        assert_eq!(ungron_with(lines.as_bytes(), ungron), sample, "{lines}");
+    }
+
+    // The incremental gron keeps numbers as written too.
+    let mut output = Vec::new();
+    Gron::new()
+        .preserve_order(true)
+        .lossless_numbers(true)
+        .reader_to_writer(sample.as_bytes(), &mut output)
+        .unwrap();
+    assert_eq!(ungron_with(&output, ungron), sample);
+}
+
+#[test]
+fn ungron_key_order() {
+    // More keys than a simd-json object keeps in order, assigned in reverse.
+    let entries: Vec<String> = (0..100).rev().map(|i| format!("\"k{i}\":{i}")).collect();
+    let lines: String = (0..100)
+        .rev()
+        .map(|i| format!("json.k{i} = {i};\n"))
+        .collect();
+    let compact = Ungron::new().indent(Indent::Compact);
+    let json = ungron_with(lines.as_bytes(), compact);
+    assert_eq!(json, format!("{{{}}}\n", entries.join(",")));
+
+    // Patching keeps the keys of the document before those added.
+    let mut output = Vec::new();
+    compact
+        .patch_to_writer(json.as_bytes(), b"json.a = 0;\njson.k5 = 5;\n", &mut output)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        format!("{{{},\"a\":0}}\n", entries.join(","))
+    );
+}
+
+#[test]
+fn incremental_numbers() {
+    let incremental = |json: &str| {
+        let mut output = Vec::new();
+        Gron::new()
+            .reader_to_writer(json.as_bytes(), &mut output)
+            .map(|()| String::from_utf8(output).unwrap())
+    };
+    let sample = "[18446744073709551615,-9223372036854775808,1.5e308,-0.5E-3]";
+    assert_eq!(incremental(sample).unwrap(), gron(sample, false));
+    // Like the parser, the incremental gron rejects what does not fit.
+    for json in [
+        "[1e400]",
+        "[18446744073709551616]",
+        "[-9223372036854775809]",
+    ] {
+        assert!(Gron::new()
+            .to_writer(&mut json.as_bytes().to_owned(), io::sink())
+            .is_err());
+        assert!(matches!(
+            incremental(json),
+            Err(gron::Error::Syntax { offset: 1, .. })
+        ));
+    }
+}
+
+#[test]
+fn incremental_syntax() {
+    let incremental = |json: &[u8]| Gron::new().reader_to_writer(json, io::sink());
+    // Nesting is limited by memory rather than by the call stack.
+    let depth = 5000;
+    let deep = ["[".repeat(depth), "1".into(), "]".repeat(depth)].concat();
+    assert!(incremental(deep.as_bytes()).is_ok());
+    assert!(matches!(
+        incremental("[".repeat(depth).as_bytes()),
+        Err(gron::Error::Syntax { offset, expected: "a value" }) if offset == depth
+    ));
+    // Like the parser, the incremental gron rejects unescaped control
+    // characters, which would otherwise break the line.
+    for json in [&b"{\"a\":\"x\ny\"}"[..], b"[\"\t\"]"] {
+        assert!(Gron::new()
+            .to_writer(&mut json.to_owned(), io::sink())
+            .is_err());
+        assert!(matches!(
+            incremental(json),
+            Err(gron::Error::Syntax {
+                expected: "an escaped control character",
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn bracket_keys_roundtrip() {
+    let sample = concat!(
+        "{\n",
+        "  \"a.b\": {\n",
+        "    \"\\\"]\": {\n",
+        "      \"x[0] y\": [\n",
+        "        {\n",
+        "          \"for\": true\n",
+        "        }\n",
+        "      ]\n",
+        "    }\n",
+        "  }\n",
+        "}\n",
+    );
+    let lines = gron(sample, true);
+    assert_eq!(
+        lines,
+        concat!(
+            "json = {};\n",
+            "json[\"a.b\"] = {};\n",
+            "json[\"a.b\"][\"\\\"]\"] = {};\n",
+            "json[\"a.b\"][\"\\\"]\"][\"x[0] y\"] = [];\n",
+            "json[\"a.b\"][\"\\\"]\"][\"x[0] y\"][0] = {};\n",
+            "json[\"a.b\"][\"\\\"]\"][\"x[0] y\"][0][\"for\"] = true;\n",
+        )
+    );
+    assert_eq!(ungron(lines.as_bytes()), sample);
+}
+
+#[test]
+fn stream_roundtrip() {
+    let sample = "{\"a\":\"x\\ny\"}\n\n[2,null,{\"b\":[]}]\n3.5\n";
+    let mut input = sample.as_bytes().to_owned();
+    let mut output = Vec::new();
+    Gron::new()
+        .color(HAVE_COLOR)
+        .lines_to_writer(&mut input, &mut output)
+        .unwrap();
+    let lines = String::from_utf8(output).unwrap();
+    assert_eq!(
+        lines,
+        concat!(
+            "json = [];\n",
+            "json[0] = {};\n",
+            "json[0].a = \"x\\ny\";\n",
+            "json[1] = [];\n",
+            "json[1][0] = 2;\n",
+            "json[1][1] = null;\n",
+            "json[1][2] = {};\n",
+            "json[1][2].b = [];\n",
+            "json[2] = 3.5;\n",
+        )
+    );
+
+    assert_eq!(
+        ungron_with(lines.as_bytes(), Ungron::new().jsonl(true)),
+        sample.replace("\n\n", "\n"),
+    );
+}
+
+#[test]
+fn named_roundtrip() {
+    let samples = [
+        ("a.json", "{\n  \"x\": 1\n}\n"),
+        ("sub/b \"2\".json", "[\n  \"y\"\n]\n"),
+    ];
+    let mut documents: Vec<(String, Vec<u8>)> = samples
+        .iter()
+        .map(|(name, json)| (name.to_string(), json.as_bytes().to_owned()))
+        .collect();
+    let mut output = Vec::new();
+    Gron::new()
+        .color(HAVE_COLOR)
+        .named_to_writer(&mut documents, &mut output)
+        .unwrap();
+    let lines = String::from_utf8(output).unwrap();
+    assert_eq!(
+        lines,
+        concat!(
+            "json = {};\n",
+            "json[\"a.json\"] = {};\n",
+            "json[\"a.json\"].x = 1;\n",
+            "json[\"sub/b \\\"2\\\".json\"] = [];\n",
+            "json[\"sub/b \\\"2\\\".json\"][0] = \"y\";\n",
+        )
+    );
+
+    let dir = std::env::temp_dir().join(format!("argon-test-{}", std::process::id()));
+    let ungron = Ungron::new();
+    let json = ungron.to_value(lines.as_bytes()).unwrap();
+    ungron.value_to_dir(&json, &dir).unwrap();
+    for (name, sample) in samples {
+        assert_eq!(std::fs::read_to_string(dir.join(name)).unwrap(), sample);
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let json = ungron.to_value(b"json[\"../a.json\"] = 1;\n").unwrap();
+    assert!(ungron.value_to_dir(&json, &dir).is_err());
+
+    // Escapes are undone in names, and in values written as yaml.
+    let yaml = Ungron::new().format(Format::Yaml);
+    let json = yaml
+        .to_value(b"json[\"\\u00e9.json\"].x = \"\\ud83d\\ude00\";\n")
+        .unwrap();
+    yaml.value_to_dir(&json, &dir).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dir.join("\u{e9}.json")).unwrap(),
+        "x: \u{1f600}\n"
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    let json = yaml.to_value(b"json[\"a\\u12.json\"] = 1;\n").unwrap();
+    assert!(matches!(
+        yaml.value_to_dir(&json, &dir),
+        Err(ungron::Error::InvalidFileName { .. })
+    ));
+    let json = yaml
+        .to_value(b"json[\"a.json\"].x = \"\\ud83d\";\n")
+        .unwrap();
+    assert!(matches!(
+        yaml.value_to_dir(&json, &dir),
+        Err(ungron::Error::Format(format::Error::InvalidString { .. }))
+    ));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn values() {
+    let sample = r#"{"b":[1,"x\ty",{}],"a":"\u0001\"\u00e9",",":null}"#;
+    let values = Gron::new().values(true);
+    assert_eq!(gron_with(sample, values), "null\n\\u0001\\\"é\n1\nx\\ty\n");
+    assert_eq!(
+        gron_with(sample, values.unescape(true)),
+        "null\n\u{1}\"é\n1\nx\ty\n"
+    );
+}
+
+#[test]
+fn ungron_formats() {
+    let lines = "json = {};\njson.b = [];\njson.b[0] = 2.0;\njson.a = {};\n".as_bytes();
+    let ungron = Ungron::new().sort_keys(true);
+    assert_eq!(
+        ungron_with(lines, ungron.indent(Indent::Compact)),
+        "{\"a\":{},\"b\":[2.0]}\n",
+    );
+    assert_eq!(
+        ungron_with(lines, ungron.indent(Indent::Spaces(4))),
+        "{\n    \"a\": {},\n    \"b\": [\n        2.0\n    ]\n}\n",
+    );
+    assert_eq!(
+        ungron_with(lines, ungron.indent(Indent::Tab)),
+        "{\n\t\"a\": {},\n\t\"b\": [\n\t\t2.0\n\t]\n}\n",
+    );
+}
+
+#[test]
+fn preserve_order_roundtrip() {
+    // Enough keys that neither simd-json nor ungron keep them in a small
+    // ordered map.
+    let keys: Vec<String> = (0..5000).rev().map(|i| format!("k{i}")).collect();
+    let mut sample = "{\n".to_owned();
+    for (i, key) in keys.iter().enumerate() {
+        let comma = if i + 1 < keys.len() { "," } else { "" };
+        sample.push_str(&format!(
+            "  \"{key}\": {{\n    \"z\": 1,\n    \"a\": 2\n  }}{comma}\n"
+        ));
+    }
+    sample.push_str("}\n");
+
+    let lines = gron_with(&sample, Gron::new().preserve_order(true));
+    assert!(lines.starts_with("json = {};\njson.k4999 = {};\njson.k4999.z = 1;\n"));
+    assert_eq!(ungron(lines.as_bytes()), sample);
+}
+
+#[test]
+fn ungron_is_deterministic() {
+    // Conflicting lines far apart, so that they end up in different chunks.
+    let mut lines = String::new();
+    for i in 0..20_000 {
+        lines.push_str(&format!("json.a[{i}] = {i};\njson.b = {};\n", i % 3));
+    }
+    for on_conflict in [
+        Conflict::FirstWins,
+        Conflict::LastWins,
+        Conflict::MergeToArray,
+    ] {
+        let ungron = Ungron::new()
+            .indent(Indent::Compact)
+            .on_conflict(on_conflict);
+        let outputs: Vec<String> = [1, 8]
+            .into_iter()
+            .map(|threads| {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap()
+                    .install(|| ungron_with(lines.as_bytes(), ungron))
+            })
+            .collect();
+        assert_eq!(outputs[0], outputs[1]);
+    }
+}
+
 #[test]
 fn gron_cases() {
-    for entry in TEST_CASES_GRON
+    check_gron_cases(&TEST_CASES_GRON, Gron::new());
+}
+
+/// The outputs of gron, which `--gron-compat` must match byte for byte.
+/// Regenerate them with gron by `nix run .#gron-compat-cases`.
+#[test]
+fn gron_compat_cases() {
+    check_gron_cases(&TEST_CASES_GRON_COMPAT, Gron::new().gron_compat(true));
+}
+
+/// Grons each `.json` file in `cases` and compares it to the `.js` file of
+/// the same name.
+fn check_gron_cases(cases: &Dir<'_>, gron: Gron) {
+    for entry in cases
         .entries()
         .iter()
         .map(|entry| entry.as_file().unwrap())
         .filter(|file| file.path().extension().unwrap() == OsStr::new("json"))
     {
         let json = entry.contents_utf8().unwrap();
-        let expected_gron = TEST_CASES_GRON
+        let expected_gron = cases
             .get_file(entry.path().with_extension("js"))
             .unwrap()
             .contents_utf8()
             .unwrap();
 
-        let got_gron = gron(&json);
+        let got_gron = gron_with(json, gron);
 
-        if &expected_gron != &got_gron {
+        if expected_gron != got_gron {
             panic!(
                 concat!(
-                    "gronning test failure\n",
+                    "gronning test failure for {:?}\n",
                     "BEGIN EXPECTED GRON\n",
                     "{}\n",
                     "END EXPECTED GRON\n",
@@ -80,7 +488,9 @@ fn gron_cases() {
                     "{}\n",
                     "END GOT GRON\n",
                 ),
-                expected_gron, got_gron
+                entry.path(),
+                expected_gron,
+                got_gron
             );
         }
     }
@@ -103,7 +513,7 @@ fn ungron_cases() {
 
         let got_json = ungron(gron.as_bytes());
 
-        if &expected_json != &got_json {
+        if expected_json != got_json {
             panic!(
                 concat!(
                     "ungronning test failure\n",
@@ -120,28 +530,265 @@ fn ungron_cases() {
     }
 }
 
-struct Output {
-    inner: Rc<RefCell<Vec<u8>>>,
-}
-impl Output {
-    fn new() -> (Box<Self>, Self) {
-        let ret = Rc::new(RefCell::new(Vec::new()));
-        (
-            Box::new(Self {
-                inner: Rc::clone(&ret),
-            }),
-            Self { inner: ret },
+#[test]
+fn select() {
+    let sample =
+        r#"{"users":[{"email":"a@b","id":1,"x":{"id":[2]}},{"name":"c"}],"id":3,"ids":{}}"#;
+    let select = |patterns: &[&str]| {
+        let patterns: Vec<select::Pattern> = patterns.iter().map(|p| p.parse().unwrap()).collect();
+        let gron = gron_with(sample, Gron::new().select(&patterns));
+        let mut output = Vec::new();
+        Gron::new()
+            .select(&patterns)
+            .reader_to_writer(sample.as_bytes(), &mut output)
+            .unwrap();
+        // The incremental gron writes keys in document order.
+        let mut sorted: Vec<&str> = gron.lines().collect();
+        sorted.sort_unstable();
+        let mut incremental: Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+        incremental.sort_unstable();
+        assert_eq!(sorted, incremental);
+        gron
+    };
+    assert_eq!(
+        select(&["json.users[*].email"]),
+        "json.users[0].email = \"a@b\";\n"
+    );
+    assert_eq!(
+        select(&["json.**.id"]),
+        concat!(
+            "json.id = 3;\n",
+            "json.users[0].id = 1;\n",
+            "json.users[0].x.id = [];\n",
+            "json.users[0].x.id[0] = 2;\n",
         )
-    }
-    fn get(self) -> String {
-        (std::str::from_utf8(&(*self.inner).borrow()).unwrap()).to_owned()
+    );
+    assert_eq!(
+        select(&["json.id*", "json.users[1]"]),
+        concat!(
+            "json.id = 3;\n",
+            "json.ids = {};\n",
+            "json.users[1] = {};\n",
+            "json.users[1].name = \"c\";\n",
+        )
+    );
+    assert_eq!(
+        select(&["json[\"users\"][0].x.*[0]"]),
+        "json.users[0].x.id[0] = 2;\n"
+    );
+    assert_eq!(select(&["json.nothing"]), "");
+
+    for invalid in ["", "json.", "json[x]", "json[\"a]", "json[0", "jsonx"] {
+        assert!(invalid.parse::<select::Pattern>().is_err(), "{invalid}");
     }
 }
-impl io::Write for Output {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        (*self.inner).borrow_mut().write(buf)
-    }
-    fn flush(&mut self) -> io::Result<()> {
-        (*self.inner).borrow_mut().flush()
+
+#[test]
+fn grep() {
+    let sample = r#"{"a":[{"b":"x"},{"b":"y"}],"bb":{"c":1}}"#;
+    let regex = |regex: &str| regex::Regex::new(regex).unwrap();
+    let (b, x) = (regex("b"), regex("^x$"));
+    assert_eq!(
+        gron_with(sample, Gron::new().grep(Some(&b))),
+        concat!(
+            "json.a[0].b = \"x\";\n",
+            "json.a[1].b = \"y\";\n",
+            "json.bb = {};\n",
+            "json.bb.c = 1;\n",
+        )
+    );
+    assert_eq!(
+        gron_with(
+            sample,
+            Gron::new().grep(Some(&x)).grep_target(GrepTarget::Value)
+        ),
+        "json.a[0].b = \"x\";\n",
+    );
+    assert_eq!(
+        gron_with(
+            sample,
+            Gron::new()
+                .grep(Some(&b))
+                .grep_target(GrepTarget::Path)
+                .invert_match(true)
+        ),
+        concat!(
+            "json = {};\n",
+            "json.a = [];\n",
+            "json.a[0] = {};\n",
+            "json.a[1] = {};\n",
+        )
+    );
+
+    let lines = gron_with(
+        sample,
+        Gron::new()
+            .grep(Some(&x))
+            .grep_target(GrepTarget::Value)
+            .ancestors(true),
+    );
+    assert_eq!(
+        lines,
+        concat!(
+            "json = {};\n",
+            "json.a = [];\n",
+            "json.a[0] = {};\n",
+            "json.a[0].b = \"x\";\n",
+        )
+    );
+    assert_eq!(
+        ungron_with(lines.as_bytes(), Ungron::new().indent(Indent::Compact)),
+        "{\"a\":[{\"b\":\"x\"}]}\n",
+    );
+}
+
+#[test]
+fn diff() {
+    let old = r#"{"a":[1,2,3],"b":{"x":1},"c":"s","d":1.5}"#;
+    let new = r#"{"a":[3,1,4],"b":[1],"c":"s","d":1.50,"e":null}"#;
+    let diff = |diff: Diff| {
+        let (mut old, mut new) = (old.as_bytes().to_owned(), new.as_bytes().to_owned());
+        let mut output = Vec::new();
+        diff.to_writer(&mut old, &mut new, &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    };
+    let changed_types = concat!(
+        "-json.b = {};\n",
+        "-json.b.x = 1;\n",
+        "+json.b = [];\n",
+        "+json.b[0] = 1;\n",
+        "+json.e = null;\n",
+    );
+    assert_eq!(
+        diff(Diff::new()),
+        [
+            concat!(
+                "-json.a[0] = 1;\n",
+                "+json.a[0] = 3;\n",
+                "-json.a[1] = 2;\n",
+                "+json.a[1] = 1;\n",
+                "-json.a[2] = 3;\n",
+                "+json.a[2] = 4;\n",
+            ),
+            changed_types
+        ]
+        .concat(),
+    );
+    assert_eq!(
+        diff(Diff::new().ignore_array_order(true)),
+        ["-json.a[1] = 2;\n+json.a[2] = 4;\n", changed_types].concat(),
+    );
+}
+
+#[test]
+fn yaml_roundtrip() {
+    let yaml =
+        "a: 1\nb: [x, 2.5, \"y\\n\"]\n---\nbase: &base {k: v}\nderived:\n  <<: *base\n  2: true\n";
+    let json = Format::Yaml.to_json(yaml.as_bytes().to_owned()).unwrap();
+    let lines = gron(std::str::from_utf8(&json).unwrap(), false);
+    assert_eq!(
+        lines,
+        concat!(
+            "json = [];\n",
+            "json[0] = {};\n",
+            "json[0].a = 1;\n",
+            "json[0].b = [];\n",
+            "json[0].b[0] = \"x\";\n",
+            "json[0].b[1] = 2.5;\n",
+            "json[0].b[2] = \"y\\n\";\n",
+            "json[1] = {};\n",
+            "json[1].base = {};\n",
+            "json[1].base.k = \"v\";\n",
+            "json[1].derived = {};\n",
+            "json[1].derived.2 = true;\n",
+            "json[1].derived.k = \"v\";\n",
+        )
+    );
+    assert_eq!(
+        ungron_with(
+            lines.as_bytes(),
+            Ungron::new().format(Format::Yaml).jsonl(true)
+        ),
+        concat!(
+            "a: 1\n",
+            "b:\n",
+            "- x\n",
+            "- 2.5\n",
+            "- |\n",
+            "  y\n",
+            "---\n",
+            "base:\n",
+            "  k: v\n",
+            "derived:\n",
+            "  '2': true\n",
+            "  k: v\n",
+        )
+    );
+}
+
+/// Lines whose strings are unescaped when writing yaml and toml, and either
+/// the output or the error message expected.
+#[test]
+fn escapes_cases() {
+    for entry in TEST_CASES_ESCAPES
+        .files()
+        .filter(|file| file.path().extension().unwrap() == OsStr::new("js"))
+    {
+        let lines = entry.contents();
+        let path = entry.path();
+        for (format, extension) in [(Format::Yaml, "yaml"), (Format::Toml, "toml")] {
+            let mut output = Vec::new();
+            let got = Ungron::new()
+                .format(format)
+                .to_writer(lines, &mut output)
+                .map(|()| String::from_utf8(output).unwrap())
+                .map_err(|err| format!("{err}\n"));
+            let expected = match TEST_CASES_ESCAPES.get_file(path.with_extension(extension)) {
+                Some(file) => Ok(file.contents_utf8().unwrap().to_owned()),
+                None => Err(TEST_CASES_ESCAPES
+                    .get_file(path.with_extension("err"))
+                    .unwrap()
+                    .contents_utf8()
+                    .unwrap()
+                    .to_owned()),
+            };
+            assert_eq!(got, expected, "{path:?} as {extension}");
+        }
     }
 }
+
+#[test]
+fn toml_roundtrip() {
+    let toml = "[package]\nname = \"x\"\ndate = 1979-05-27\n\n[[bin]]\nname = \"a\"\n";
+    let json = Format::Toml.to_json(toml.as_bytes().to_owned()).unwrap();
+    let lines = gron(std::str::from_utf8(&json).unwrap(), false);
+    assert_eq!(
+        lines,
+        concat!(
+            "json = {};\n",
+            "json.bin = [];\n",
+            "json.bin[0] = {};\n",
+            "json.bin[0].name = \"a\";\n",
+            "json.package = {};\n",
+            "json.package.date = \"1979-05-27\";\n",
+            "json.package.name = \"x\";\n",
+        )
+    );
+    let to_toml = |lines: &str| {
+        let mut output = Vec::new();
+        Ungron::new()
+            .format(Format::Toml)
+            .to_writer(lines.as_bytes(), &mut output)
+            .map(|()| String::from_utf8(output).unwrap())
+            .map_err(|err| err.to_string())
+    };
+    assert_eq!(
+        to_toml(&lines).unwrap(),
+        "[[bin]]\nname = \"a\"\n\n[package]\ndate = \"1979-05-27\"\nname = \"x\"\n",
+    );
+    assert_eq!(
+        to_toml("json.a[0] = null;\n").unwrap_err(),
+        "cannot write `json[\"a\"][0]` as toml: toml has no null",
+    );
+    assert!(to_toml("json = [];\n").is_err());
+}
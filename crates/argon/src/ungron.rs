@@ -1,131 +1,1420 @@
-use patched_simd_json::{value::borrowed::Value, StaticNode};
+//! Converting assignment statements back into json.
+
+use crate::format::{self, Format};
+use indexmap::IndexMap;
+use patched_simd_json::{
+    value::{tape::Node, ObjectHasher},
+    StaticNode,
+};
 use rayon::{iter::ParallelIterator, str::ParallelString};
 use std::{
     borrow::Cow,
-    io::{BufWriter, Write},
-    mem::{self, ManuallyDrop},
+    fmt, fs,
+    io::{self, BufWriter, Write},
+    mem,
+    path::{Component, Path},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
 };
 
-pub fn process(data: &[u8], output: Box<dyn Write>) -> Result<(), ()> {
-    if data.is_empty() {
-        tracing::error!("got EOF");
-        return Err(());
+/// Converts assignment statements, one per line, back into json. The lines
+/// may come in any order.
+///
+/// ```
+/// let lines = b"json.b[1] = true;\njson.a = \"x\";\n";
+/// let mut output = Vec::new();
+/// argon::Ungron::new()
+///     .indent(argon::ungron::Indent::Compact)
+///     .to_writer(lines, &mut output)
+///     .unwrap();
+/// assert_eq!(output, b"{\"b\":[null,true],\"a\":\"x\"}\n");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ungron {
+    indent: Indent,
+    sort_keys: bool,
+    compact_arrays: bool,
+    lenient: bool,
+    jsonl: bool,
+    on_conflict: Conflict,
+    format: Format,
+    lossless_numbers: bool,
+}
+impl Ungron {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn indent(mut self, indent: Indent) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Write object keys in sorted order rather than in the order they were
+    /// first assigned.
+    pub fn sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+
+    /// Drop array elements that no line assigns to instead of filling them
+    /// with null.
+    pub fn compact_arrays(mut self, compact_arrays: bool) -> Self {
+        self.compact_arrays = compact_arrays;
+        self
+    }
+
+    /// Skip and log invalid lines instead of failing.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Write each element of the top-level array as a line of json, or as a
+    /// document of yaml.
+    pub fn jsonl(mut self, jsonl: bool) -> Self {
+        self.jsonl = jsonl;
+        self
+    }
+
+    /// How to settle lines that assign different values to the same path.
+    pub fn on_conflict(mut self, on_conflict: Conflict) -> Self {
+        self.on_conflict = on_conflict;
+        self
+    }
+
+    /// The format to write, json by default.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Keep numbers exactly as they are written in the lines, rather than
+    /// as parsed into a 64-bit integer or float. They are still parsed for
+    /// yaml and toml.
+    ///
+    /// ```
+    /// let lines = b"json[0] = 18446744073709551616;\njson[1] = 1.10;\njson[2] = 1e400;\n";
+    /// let mut output = Vec::new();
+    /// argon::Ungron::new()
+    ///     .indent(argon::ungron::Indent::Compact)
+    ///     .lossless_numbers(true)
+    ///     .to_writer(lines, &mut output)
+    ///     .unwrap();
+    /// assert_eq!(output, b"[18446744073709551616,1.10,1e400]\n");
+    /// ```
+    pub fn lossless_numbers(mut self, lossless_numbers: bool) -> Self {
+        self.lossless_numbers = lossless_numbers;
+        self
+    }
+
+    /// Ungrons `lines` and writes the json.
+    pub fn to_writer(&self, lines: &[u8], output: impl Write) -> Result<(), Error> {
+        let json = self.to_value(lines)?;
+        self.value_to_writer(&json, output)
+    }
+
+    /// Ungrons `lines` into a value borrowing from them. Strings are kept in
+    /// their escaped form.
+    pub fn to_value<'a>(&self, lines: &'a [u8]) -> Result<Value<'a>, Error> {
+        if lines.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let data = std::str::from_utf8(lines).map_err(|err| {
+            let (line, column) = line_and_column(lines, err.valid_up_to());
+            Error::InvalidUtf8 { line, column }
+        })?;
+
+        // Lines are first added in parallel, which is only valid while no
+        // lines conflict. Otherwise, the order of the lines settles the
+        // conflicts, so the work is redone sequentially.
+        let skipped = Mutex::new(Vec::new());
+        let holes = AtomicUsize::new(hole_budget(data));
+        let mode = Mode::new(&holes, self.lossless_numbers);
+        let json = data
+            .par_lines()
+            .try_fold_with(hole(), |json, line| {
+                add_line(json, line, *self, mode, None, &skipped).map_err(drop)
+            })
+            .try_reduce(hole, |j1, j2| merge_json(j1, j2).ok_or(()));
+        let mut json = match json {
+            Ok(json) => json,
+            Err(()) => {
+                skipped.lock().unwrap().clear();
+                holes.store(hole_budget(data), Ordering::Relaxed);
+                data.lines()
+                    .try_fold(hole(), |json, line| {
+                        add_line(json, line, *self, mode, Some(self.on_conflict), &skipped)
+                    })
+                    .map_err(|err| err.locate(data))?
+            }
+        };
+        finish(&mut json, self.compact_arrays);
+
+        let mut skipped = skipped.into_inner().unwrap();
+        skipped.sort_unstable_by_key(|err| err.at.as_ptr());
+        for err in skipped {
+            let err = err.locate(data);
+            tracing::warn!(%err, "skipping invalid line");
+        }
+        Ok(json)
+    }
+
+    /// Applies `lines` to the json document `json` in order and writes the
+    /// result. Unlike when ungronning, later lines replace what earlier lines
+    /// or the document assigned, and `json.a = undefined;` deletes `json.a`.
+    /// Deleted array elements are removed once all lines are applied, so
+    /// indices refer to the elements before patching. Assigning `{}` or `[]`
+    /// to an object or array keeps what it holds, as when ungronning.
+    ///
+    /// ```
+    /// let json = br#"{"a":[1,2,3],"b":{"c":true}}"#;
+    /// let lines = b"json.b.c = false;\njson.a[0] = undefined;\njson.d = \"x\";\n";
+    /// let mut output = Vec::new();
+    /// argon::Ungron::new()
+    ///     .indent(argon::ungron::Indent::Compact)
+    ///     .patch_to_writer(json, lines, &mut output)
+    ///     .unwrap();
+    /// assert_eq!(output, b"{\"a\":[2,3],\"b\":{\"c\":false},\"d\":\"x\"}\n");
+    /// ```
+    pub fn patch_to_writer(
+        &self,
+        json: &[u8],
+        lines: &[u8],
+        output: impl Write,
+    ) -> Result<(), Error> {
+        let (mut json, numbers) = if self.lossless_numbers {
+            crate::gron::quote_numbers(json)
+        } else {
+            (json.to_vec(), Vec::new())
+        };
+        let base = json.as_ptr() as usize;
+        let numbers: Vec<usize> = numbers.into_iter().map(|i| base + i).collect();
+        let tape = patched_simd_json::to_tape(&mut json).map_err(Error::Json)?;
+        let mut json = from_tape(&mut tape.0.into_iter(), &numbers);
+        let data = std::str::from_utf8(lines).map_err(|err| {
+            let (line, column) = line_and_column(lines, err.valid_up_to());
+            Error::InvalidUtf8 { line, column }
+        })?;
+        let holes = AtomicUsize::new(hole_budget(data));
+        let mode = Mode {
+            patch: true,
+            ..Mode::new(&holes, self.lossless_numbers)
+        };
+        for line in data.lines() {
+            patch_line(&mut json, line, mode).map_err(|err| err.locate(data))?;
+        }
+        remove_deleted(&mut json);
+        finish(&mut json, self.compact_arrays);
+        self.value_to_writer(&json, output)
+    }
+
+    /// Writes a value produced by [`Ungron::to_value`].
+    pub fn value_to_writer(&self, json: &Value<'_>, output: impl Write) -> Result<(), Error> {
+        let mut output = BufWriter::new(output);
+        if self.format == Format::Toml {
+            if self.jsonl {
+                return Err(Error::Format(format::Error::NotToml {
+                    path: "json".to_owned(),
+                    reason: "toml has no streams of several documents",
+                }));
+            }
+            let toml = format::to_toml(json, self.sort_keys).map_err(Error::Format)?;
+            output.write_all(toml.as_bytes())?;
+        } else if self.format == Format::Yaml {
+            if self.jsonl && !matches!(json, Value::Array(_)) {
+                return Err(Error::NotAnArray {
+                    found: type_name(json),
+                });
+            }
+            format::write_yaml(&mut output, json, self.sort_keys, self.jsonl)
+                .map_err(Error::Format)?;
+        } else if self.jsonl {
+            let Value::Array(array) = json else {
+                return Err(Error::NotAnArray {
+                    found: type_name(json),
+                });
+            };
+            for item in array.iter() {
+                write_json(&mut output, item, Indent::Compact, self.sort_keys, 0)?;
+                output.write_all(b"\n")?;
+            }
+        } else {
+            write_json(&mut output, json, self.indent, self.sort_keys, 0)?;
+            output.write_all(b"\n")?;
+        }
+        Ok(output.flush()?)
+    }
+
+    /// Writes each entry of the top-level object to its own file in `dir`,
+    /// named by its key. This undoes [`crate::Gron::named_to_writer`].
+    pub fn value_to_dir(&self, json: &Value<'_>, dir: &Path) -> Result<(), Error> {
+        let Value::Object(object) = json else {
+            return Err(Error::NotAnObject {
+                found: type_name(json),
+            });
+        };
+        for (name, value) in object.iter() {
+            let Ok(name) = crate::gron::unescape(name) else {
+                return Err(Error::InvalidFileName {
+                    name: name.to_string(),
+                });
+            };
+            let relative = Path::new(&*name);
+            let mut components = relative.components().filter(|&c| c != Component::CurDir);
+            if !components.all(|c| matches!(c, Component::Normal(_)))
+                || relative.file_name().is_none()
+            {
+                return Err(Error::InvalidFileName {
+                    name: name.into_owned(),
+                });
+            }
+            let path = dir.join(relative);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            self.value_to_writer(value, fs::File::create(path)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Json as ungronned. Like the borrowed value of simd-json, strings are kept in
+/// their escaped form and only numbers kept as written are owned strings, but
+/// objects keep their keys in the order they were first assigned.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'a> {
+    Static(StaticNode),
+    String(Cow<'a, str>),
+    Array(Vec<Value<'a>>),
+    Object(Box<Object<'a>>),
+}
+
+/// The entries of an object, in insertion order.
+pub type Object<'a> = IndexMap<Cow<'a, str>, Value<'a>, ObjectHasher>;
+
+/// How to indent the json output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Indent {
+    /// Everything on a single line.
+    Compact,
+    Spaces(usize),
+    Tab,
+}
+impl Default for Indent {
+    fn default() -> Self {
+        Indent::Spaces(2)
+    }
+}
+impl FromStr for Indent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tab" => Ok(Indent::Tab),
+            _ => s
+                .parse()
+                .map(Indent::Spaces)
+                .map_err(|_| format!("expected a number of spaces or `tab`, got `{s}`")),
+        }
+    }
+}
+
+/// How to settle lines that assign different values to the same path. Lines
+/// that assign the same scalar or the same kind of container never conflict.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Conflict {
+    /// Fail with [`Error::Conflict`].
+    #[default]
+    Error,
+    /// Keep the value from the earliest line and skip later conflicting lines.
+    FirstWins,
+    /// Replace the value with the one from the later line.
+    LastWins,
+    /// Collect the conflicting values into an array, in line order.
+    MergeToArray,
+}
+impl FromStr for Conflict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Conflict::Error),
+            "first-wins" => Ok(Conflict::FirstWins),
+            "last-wins" => Ok(Conflict::LastWins),
+            "merge-to-array" => Ok(Conflict::MergeToArray),
+            _ => Err(format!(
+                "expected `error`, `first-wins`, `last-wins` or `merge-to-array`, got `{s}`"
+            )),
+        }
     }
+}
 
-    let data = std::str::from_utf8(data).unwrap();
-    let json = data
-        .par_lines()
-        .fold_with(Value::Static(StaticNode::Null), add_line_to_json)
-        .reduce(|| Value::Static(StaticNode::Null), merge_json);
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The document given to [`Ungron::patch_to_writer`] is not valid json.
+    Json(patched_simd_json::Error),
+    /// The value could not be written in the chosen [`Format`].
+    Format(format::Error),
+    Empty,
+    InvalidUtf8 {
+        line: usize,
+        column: usize,
+    },
+    Syntax {
+        line: usize,
+        column: usize,
+        expected: &'static str,
+    },
+    Conflict {
+        line: usize,
+        column: usize,
+        expected: &'static str,
+        found: &'static str,
+    },
+    NotAnArray {
+        found: &'static str,
+    },
+    NotAnObject {
+        found: &'static str,
+    },
+    /// A top-level key is not a relative path within the output directory.
+    InvalidFileName {
+        name: String,
+    },
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Json(err) => write!(f, "invalid json: {err}"),
+            Error::Format(err) => write!(f, "{err}"),
+            Error::Empty => write!(f, "got EOF"),
+            Error::InvalidUtf8 { line, column } => {
+                write!(f, "line {line}, column {column}: invalid utf8")
+            }
+            Error::Syntax {
+                line,
+                column,
+                expected,
+            } => write!(f, "line {line}, column {column}: expected {expected}"),
+            Error::Conflict {
+                line,
+                column,
+                expected,
+                found,
+            } if expected == found => write!(
+                f,
+                "line {line}, column {column}: an earlier line assigned a different value"
+            ),
+            Error::Conflict {
+                line,
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line}, column {column}: expected {expected} but an earlier line made it {found}"
+            ),
+            Error::NotAnArray { found } => {
+                write!(f, "expected an array to write as lines but got {found}")
+            }
+            Error::NotAnObject { found } => {
+                write!(f, "expected an object to split into files but got {found}")
+            }
+            Error::InvalidFileName { name } => {
+                write!(f, "cannot write {name:?} as a file in the output directory")
+            }
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::Format(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Writes `json` at nesting level `depth`. Strings are kept in their escaped
+/// form, like the patched pretty printer does.
+fn write_json(
+    output: &mut impl Write,
+    json: &Value<'_>,
+    indent: Indent,
+    sort_keys: bool,
+    depth: usize,
+) -> io::Result<()> {
+    match json {
+        Value::Static(val) => patched_simd_json::to_writer(
+            &mut *output,
+            &patched_simd_json::BorrowedValue::Static(*val),
+        )
+        .map_err(io::Error::other)?,
+        Value::String(Cow::Owned(num)) => output.write_all(num.as_bytes())?,
+        Value::String(val) => write!(output, "\"{val}\"")?,
+        Value::Array(array) if array.is_empty() => output.write_all(b"[]")?,
+        Value::Array(array) => {
+            output.write_all(b"[")?;
+            for (i, item) in array.iter().enumerate() {
+                if i > 0 {
+                    output.write_all(b",")?;
+                }
+                write_newline(output, indent, depth + 1)?;
+                write_json(output, item, indent, sort_keys, depth + 1)?;
+            }
+            write_newline(output, indent, depth)?;
+            output.write_all(b"]")?;
+        }
+        Value::Object(object) if object.is_empty() => output.write_all(b"{}")?,
+        Value::Object(object) => {
+            output.write_all(b"{")?;
+            for (i, (key, value)) in entries(object, sort_keys).into_iter().enumerate() {
+                if i > 0 {
+                    output.write_all(b",")?;
+                }
+                write_newline(output, indent, depth + 1)?;
+                if indent == Indent::Compact {
+                    write!(output, "\"{key}\":")?;
+                } else {
+                    write!(output, "\"{key}\": ")?;
+                }
+                write_json(output, value, indent, sort_keys, depth + 1)?;
+            }
+            write_newline(output, indent, depth)?;
+            output.write_all(b"}")?;
+        }
+    }
+    Ok(())
+}
 
-    let mut output = BufWriter::new(output);
-    patched_simd_json::to_writer_pretty(&mut output, &json).unwrap();
-    output.write_all(b"\n").unwrap();
-    output.flush().unwrap();
+/// The entries of `object` in the order to write them.
+pub(crate) fn entries<'v, 'a>(
+    object: &'v Object<'a>,
+    sort_keys: bool,
+) -> Vec<(&'v str, &'v Value<'a>)> {
+    let mut entries: Vec<(&str, &Value<'_>)> =
+        object.iter().map(|(k, v)| (k.as_ref(), v)).collect();
+    if sort_keys {
+        entries.sort_unstable_by_key(|&(k, _)| k);
+    }
+    entries
+}
 
-    // Leak `json` for quicker exit
-    let _ = ManuallyDrop::new(json);
+fn write_newline(output: &mut impl Write, indent: Indent, depth: usize) -> io::Result<()> {
+    const SPACES: &[u8] = &[b' '; 64];
+    match indent {
+        Indent::Compact => {}
+        Indent::Spaces(width) => {
+            output.write_all(b"\n")?;
+            let mut n = width * depth;
+            while n > 0 {
+                let chunk = n.min(SPACES.len());
+                output.write_all(&SPACES[..chunk])?;
+                n -= chunk;
+            }
+        }
+        Indent::Tab => {
+            output.write_all(b"\n")?;
+            for _ in 0..depth {
+                output.write_all(b"\t")?;
+            }
+        }
+    }
     Ok(())
 }
 
-fn add_line_to_json<'a>(mut json: Value<'a>, line: &'a str) -> Value<'a> {
-    let line = line.strip_prefix("json").unwrap();
-    add_line_to_json_impl(&mut json, line);
-    json
+/// Adds `line` to `json`, settling conflicts by `on_conflict`, or failing on
+/// them if it is `None`.
+fn add_line<'a>(
+    mut json: Value<'a>,
+    line: &'a str,
+    ungron: Ungron,
+    mode: Mode<'_>,
+    on_conflict: Option<Conflict>,
+    skipped: &Mutex<Vec<LineError<'a>>>,
+) -> Result<Value<'a>, LineError<'a>> {
+    let conflict = on_conflict.unwrap_or(Conflict::Error);
+    if ungron.lenient {
+        // Validate the line on a scratch value first, since a syntax error can
+        // be found after parts of the line have been inserted. Its holes are
+        // dropped again, so they do not count against the budget.
+        let scratch_holes = AtomicUsize::new(mode.holes.load(Ordering::Relaxed));
+        let scratch_mode = Mode {
+            holes: &scratch_holes,
+            ..mode
+        };
+        let result = add_line_to_json(&mut hole(), line, Conflict::Error, scratch_mode)
+            .and_then(|()| add_line_to_json(&mut json, line, conflict, mode));
+        match result {
+            Ok(()) => {}
+            Err(err) if err.found.is_some() && on_conflict.is_none() => return Err(err),
+            Err(err) => skipped.lock().unwrap().push(err),
+        }
+        Ok(json)
+    } else {
+        add_line_to_json(&mut json, line, conflict, mode)?;
+        Ok(json)
+    }
+}
+
+/// An invalid line, pointing into the input at where the problem is.
+struct LineError<'a> {
+    at: &'a str,
+    expected: &'static str,
+    found: Option<&'static str>,
+}
+impl<'a> LineError<'a> {
+    fn syntax(at: &'a str, expected: &'static str) -> Self {
+        Self {
+            at,
+            expected,
+            found: None,
+        }
+    }
+    fn conflict(at: &'a str, expected: &'static str, found: &Value<'_>) -> Self {
+        Self {
+            at,
+            expected,
+            found: Some(type_name(found)),
+        }
+    }
+    fn locate(self, data: &str) -> Error {
+        let (line, column) = line_and_column(
+            data.as_bytes(),
+            self.at.as_ptr() as usize - data.as_ptr() as usize,
+        );
+        match self.found {
+            None => Error::Syntax {
+                line,
+                column,
+                expected: self.expected,
+            },
+            Some(found) => Error::Conflict {
+                line,
+                column,
+                expected: self.expected,
+                found,
+            },
+        }
+    }
+}
+
+/// One-based line and byte column of `offset` within `data`.
+fn line_and_column(data: &[u8], offset: usize) -> (usize, usize) {
+    let before = &data[..offset];
+    let line_start = memchr::memrchr(b'\n', before).map_or(0, |i| i + 1);
+    (
+        1 + memchr::memchr_iter(b'\n', before).count(),
+        1 + offset - line_start,
+    )
+}
+
+fn type_name(json: &Value<'_>) -> &'static str {
+    match json {
+        Value::Static(StaticNode::Null) => "null",
+        Value::Static(StaticNode::Bool(_)) => "a boolean",
+        Value::Static(_) | Value::String(Cow::Owned(_)) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+/// The literal of a number kept as written by [`Ungron::lossless_numbers`] or
+/// [`Gron::lossless_numbers`](crate::Gron::lossless_numbers). Their values
+/// borrow all strings from the input, so only these numbers are owned.
+pub(crate) fn lossless_number<'v>(json: &'v Value<'_>) -> Option<&'v str> {
+    match json {
+        Value::String(Cow::Owned(num)) => Some(num),
+        _ => None,
+    }
+}
+
+/// Whether two scalars are equal, telling numbers kept as written apart from
+/// strings with the same text.
+pub(crate) fn same_scalar(s1: &Value<'_>, s2: &Value<'_>) -> bool {
+    s1 == s2 && lossless_number(s1).is_some() == lossless_number(s2).is_some()
+}
+
+/// Marks a value that no line has assigned to yet, so that it cannot be
+/// confused with an assigned `null`. It is recognized by address, so it cannot
+/// be confused with a string from the input either.
+static HOLE: &str = "<hole>";
+/// Marks an array as collecting conflicting values, by being its first
+/// element. Later lines add to its last element.
+static MERGED: &str = "<merged>";
+/// Marks a value deleted by [`Ungron::patch_to_writer`], to be removed once
+/// all lines are applied.
+static DELETED: &str = "<deleted>";
+
+fn hole<'a>() -> Value<'a> {
+    Value::String(Cow::Borrowed(HOLE))
+}
+fn is_hole(json: &Value<'_>) -> bool {
+    matches!(json, Value::String(Cow::Borrowed(s)) if s.as_ptr() == HOLE.as_ptr())
+}
+fn is_deleted(json: &Value<'_>) -> bool {
+    matches!(json, Value::String(Cow::Borrowed(s)) if s.as_ptr() == DELETED.as_ptr())
+}
+fn is_merge_marker(json: &Value<'_>) -> bool {
+    matches!(json, Value::String(Cow::Borrowed(s)) if s.as_ptr() == MERGED.as_ptr())
+}
+fn is_merged(json: &Value<'_>) -> bool {
+    matches!(json, Value::Array(array) if array.first().is_some_and(is_merge_marker))
+}
+
+/// The value that later lines assign into, which for conflicting values
+/// collected into an array is the last of them.
+fn current<'a, 'b>(json: &'b Value<'a>) -> &'b Value<'a> {
+    match json {
+        Value::Array(array) if is_merged(json) => array.last().unwrap(),
+        _ => json,
+    }
+}
+fn current_mut<'a, 'b>(json: &'b mut Value<'a>) -> &'b mut Value<'a> {
+    if !is_merged(json) {
+        return json;
+    }
+    let Value::Array(array) = json else {
+        unreachable!()
+    };
+    array.last_mut().unwrap()
+}
+
+/// The value to go on into for a line that needs `expected` at `json`. Returns
+/// `None` if the line should be skipped.
+fn make_room<'a, 'b>(
+    json: &'b mut Value<'a>,
+    line: &'a str,
+    expected: &'static str,
+    on_conflict: Conflict,
+) -> Result<Option<&'b mut Value<'a>>, LineError<'a>> {
+    let found = current(json);
+    if is_hole(found) || type_name(found) == expected {
+        return Ok(Some(current_mut(json)));
+    }
+    let err = LineError::conflict(line, expected, found);
+    settle(json, on_conflict, err)
+}
+
+/// Settles a conflict at `json` by `on_conflict`. Returns `None` if the line
+/// should be skipped, and otherwise the vacant value to assign to.
+fn settle<'a, 'b>(
+    json: &'b mut Value<'a>,
+    on_conflict: Conflict,
+    err: LineError<'a>,
+) -> Result<Option<&'b mut Value<'a>>, LineError<'a>> {
+    match on_conflict {
+        Conflict::Error => Err(err),
+        Conflict::FirstWins => Ok(None),
+        Conflict::LastWins => {
+            *json = hole();
+            Ok(Some(json))
+        }
+        Conflict::MergeToArray => {
+            if !is_merged(json) {
+                let prev = mem::replace(json, hole());
+                *json = Value::Array(vec![Value::String(Cow::Borrowed(MERGED)), prev]);
+            }
+            let Value::Array(array) = json else {
+                unreachable!()
+            };
+            array.push(hole());
+            Ok(array.last_mut())
+        }
+    }
+}
+
+/// What lines may contain besides plain assignments.
+#[derive(Clone, Copy, Debug)]
+struct Mode<'m> {
+    /// `= undefined;`, for [`Ungron::patch_to_writer`].
+    patch: bool,
+    /// Numbers to keep as written, for [`Ungron::lossless_numbers`].
+    lossless_numbers: bool,
+    /// How many more array elements may be filled in as holes before the
+    /// indices that lines assign. Shared by all lines, so that it bounds the
+    /// memory of the whole input.
+    holes: &'m AtomicUsize,
+}
+impl<'m> Mode<'m> {
+    fn new(holes: &'m AtomicUsize, lossless_numbers: bool) -> Self {
+        Self {
+            patch: false,
+            lossless_numbers,
+            holes,
+        }
+    }
+}
+
+/// The holes that lines from `data` may leave in arrays altogether, which
+/// allows an index past every line it has, but not much further.
+fn hole_budget(data: &str) -> usize {
+    data.len().max(MIN_HOLES)
 }
-fn add_line_to_json_impl<'a>(mut json: &mut Value<'a>, mut line: &'a str) {
+
+/// The holes allowed regardless of the size of the input, so that lines
+/// filtered out of long arrays still ungron.
+const MIN_HOLES: usize = 1 << 20;
+
+fn add_line_to_json<'a>(
+    json: &mut Value<'a>,
+    line: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    add_any_line(json, line, on_conflict, mode)
+}
+/// Applies `line` to `json` for [`Ungron::patch_to_writer`].
+fn patch_line<'a>(
+    json: &mut Value<'a>,
+    line: &'a str,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    if let Some(root) = ["json", "", "$"]
+        .into_iter()
+        .find(|root| line.strip_prefix(root) == Some(" = undefined;"))
+    {
+        return Err(LineError::syntax(
+            &line[root.len()..],
+            "a path below the document to delete",
+        ));
+    }
+    add_any_line(json, line, Conflict::LastWins, mode)
+}
+/// Adds a line with a path in any [`PathSyntax`](crate::gron::PathSyntax),
+/// told apart by its first character.
+fn add_any_line<'a>(
+    json: &mut Value<'a>,
+    line: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    match line.as_bytes().first() {
+        Some(b'/' | b' ' | b'\t') => add_pointer_line(json, line, on_conflict, mode),
+        Some(b'$') => add_json_path_line(json, line, on_conflict, mode),
+        Some(b'[') => add_json_line(json, line, on_conflict, mode),
+        _ => match line.strip_prefix("json") {
+            Some(rest) => add_line_to_json_impl(json, rest, on_conflict, mode),
+            None => Err(LineError::syntax(line, "`json`, `/` or `$`")),
+        },
+    }
+}
+fn add_line_to_json_impl<'a>(
+    mut json: &mut Value<'a>,
+    line: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
     // Grammar-ish:
-    // `line = "json" path* " = " item ","`
-    // `path = "." [^\.\[ ]*`
+    // `line = "json" path* " = " item ";" | "json" path* "\t" type "\t" value`
+    // `path = "." [^\.\[ ]* | "[" [0-9]+ "]" | '["' key '"]'`
     // `item = "{}" | "[]" | '"blah"' | 12345 | null`
+    //
+    // Records are told apart by their tab, which assignments cannot contain
+    // as strings are escaped. Keys of records end only at `.` or `[`.
+    //
+    // A conflict can only be found before anything is inserted, since
+    // everything below an inserted node is vacant. Syntax errors can however
+    // be found after inserting, so the caller must discard `json` on error
+    // unless it has validated the line first.
+    let (mut line, record) = match memchr::memchr(b'\t', line.as_bytes()) {
+        Some(tab) => (&line[..tab], Some(&line[tab..])),
+        None => (line, None),
+    };
     loop {
         let bytes = line.as_bytes();
-        match bytes.first().unwrap() {
-            b'[' => {
-                if bytes[1] == b'"' {
-                    unimplemented!("non-integer square bracket access");
+        let room = match bytes.first() {
+            Some(b'[') if bytes.get(1) == Some(&b'"') => {
+                let Some(len) = quoted_len(&bytes[2..], b'"') else {
+                    return Err(LineError::syntax(&line[bytes.len()..], "closing `\"`"));
+                };
+                let end = 2 + len;
+                if bytes.get(end + 1) != Some(&b']') {
+                    return Err(LineError::syntax(&line[end + 1..], "`]`"));
+                }
+                let key = Cow::Borrowed(&line[2..end]);
+                let room = enter_key(json, line, key, on_conflict)?;
+                line = &line[end + 2..];
+                room
+            }
+            Some(b'[') => {
+                let Some(end) = memchr::memchr(b']', bytes) else {
+                    return Err(LineError::syntax(&line[bytes.len()..], "`]`"));
+                };
+                let Ok(index) = line[1..end].parse::<usize>() else {
+                    return Err(LineError::syntax(&line[1..], "an array index"));
+                };
+                let room = enter_index(json, line, index, on_conflict, mode)?;
+                line = &line[end + 1..];
+                room
+            }
+            Some(b'.') => {
+                let next = if record.is_some() {
+                    memchr::memchr2(b'[', b'.', &bytes[1..]).unwrap_or(bytes.len() - 1)
                 } else {
-                    if let Value::Static(StaticNode::Null) = json {
-                        *json = Value::Array(Vec::new());
+                    let Some(next) = memchr::memchr3(b'[', b'.', b' ', &bytes[1..]) else {
+                        return Err(LineError::syntax(&line[bytes.len()..], "` = `"));
+                    };
+                    next
+                };
+                let next = 1 + next;
+                let key = Cow::Borrowed(&line[1..next]);
+                let room = enter_key(json, line, key, on_conflict)?;
+                line = &line[next..];
+                room
+            }
+            Some(b' ') if record.is_none() => return assign(json, line, on_conflict, mode),
+            _ => {
+                return match record {
+                    Some(fields) if line.is_empty() => {
+                        assign_record(json, fields, on_conflict, mode)
                     }
-                    let Value::Array(v) = json else { unreachable!() };
-                    v.push(Value::Static(StaticNode::Null));
-
-                    let end = memchr::memchr(b']', bytes).unwrap();
-                    line = &line[end + 1..];
-                    json = v.last_mut().unwrap();
+                    Some(_) => Err(LineError::syntax(line, "`.`, `[` or a tab")),
+                    None => Err(LineError::syntax(line, "`.`, `[` or ` = `")),
                 }
             }
-            b'.' => {
-                if let Value::Static(StaticNode::Null) = json {
-                    *json = Value::Object(Box::default());
-                }
-                let Value::Object(o) = json else { unreachable!() };
+        };
+        let Some(room) = room else {
+            return Ok(());
+        };
+        json = room;
+    }
+}
 
-                let next = 1 + memchr::memchr3(b'[', b'.', b' ', &bytes[1..]).unwrap();
+/// Adds a line whose path is a JSON Pointer, like `/a/0 = 1;`. A reference
+/// token of digits is an array index unless an object is already there.
+fn add_pointer_line<'a>(
+    mut json: &mut Value<'a>,
+    line: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    // Keys may contain ` = `, but only a real assignment follows the last
+    // reference token, since keys are escaped.
+    let path_len = match memchr::memchr(b'\t', line.as_bytes()) {
+        Some(tab) => Some(tab),
+        None => memchr::memmem::find_iter(line.as_bytes(), " = ")
+            .find(|&i| is_assignment(&line[i..], mode)),
+    };
+    let Some(path_len) = path_len else {
+        return Err(LineError::syntax(
+            line,
+            "a JSON Pointer followed by ` = ` or a tab",
+        ));
+    };
+    let (path, rest) = line.split_at(path_len);
+    let mut tokens = path.split('/');
+    if tokens.next() != Some("") {
+        return Err(LineError::syntax(line, "`/`"));
+    }
+    for token in tokens {
+        let is_index =
+            token.bytes().all(|b| b.is_ascii_digit()) && (token == "0" || !token.starts_with('0'));
+        let index = is_index.then(|| token.parse::<usize>().ok()).flatten();
+        let room = match index {
+            Some(index) if !matches!(current(json), Value::Object(_)) => {
+                enter_index(json, token, index, on_conflict, mode)?
+            }
+            _ if token.contains('~') => {
+                let key = token.replace("~1", "/").replace("~0", "~");
+                enter_key(json, token, Cow::Owned(key), on_conflict)?
+            }
+            _ => enter_key(json, token, Cow::Borrowed(token), on_conflict)?,
+        };
+        let Some(room) = room else {
+            return Ok(());
+        };
+        json = room;
+    }
+    if rest.starts_with('\t') {
+        assign_record(json, rest, on_conflict, mode)
+    } else {
+        assign(json, rest, on_conflict, mode)
+    }
+}
 
-                json = o
-                    .entry(Cow::Borrowed(&line[1..next]))
-                    .or_insert(Value::Static(StaticNode::Null));
-                line = &line[next..];
+/// Whether `line` is the ` = item;` of an assignment.
+fn is_assignment(line: &str, mode: Mode<'_>) -> bool {
+    let Some(item) = line
+        .strip_prefix(" = ")
+        .and_then(|item| item.strip_suffix(';'))
+    else {
+        return false;
+    };
+    match item {
+        "{}" | "[]" | "null" | "true" | "false" => true,
+        "undefined" => mode.patch,
+        _ if item.starts_with('"') => {
+            quoted_len(&item.as_bytes()[1..], b'"') == Some(item.len() - 2)
+        }
+        _ => parse_number(item).is_some(),
+    }
+}
+
+/// Adds a line whose path is a normalized JSONPath, like `$['a'][0] = 1;`.
+fn add_json_path_line<'a>(
+    mut json: &mut Value<'a>,
+    line: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    let mut rest = &line[1..];
+    while let Some(after_bracket) = rest.strip_prefix('[') {
+        let room = if let Some(quoted) = after_bracket.strip_prefix('\'') {
+            let Some(len) = quoted_len(quoted.as_bytes(), b'\'') else {
+                return Err(LineError::syntax(&line[line.len()..], "closing `'`"));
+            };
+            if quoted.as_bytes().get(len + 1) != Some(&b']') {
+                return Err(LineError::syntax(&quoted[len + 1..], "`]`"));
             }
-            b' ' => {
-                match line.as_bytes()[3] {
-                    b'"' => {
-                        *json = Value::String(Cow::Borrowed(
-                            line.strip_prefix(" = \"")
-                                .unwrap()
-                                .strip_suffix("\";")
-                                .unwrap(),
-                        ));
-                    }
-                    b'n' | b'{' | b'[' => {}
-                    b't' => *json = Value::Static(StaticNode::Bool(true)),
-                    b'f' => *json = Value::Static(StaticNode::Bool(false)),
-                    _ => {
-                        let digits = line.strip_prefix(" = ").unwrap().strip_suffix(";").unwrap();
-                        let node = Err(digits)
-                            .or_else(|digits| match digits.parse::<u64>() {
-                                Ok(num) => Ok(StaticNode::from(num)),
-                                Err(_) => Err(digits),
-                            })
-                            .or_else(|digits| match digits.parse::<i64>() {
-                                Ok(num) => Ok(StaticNode::from(num)),
-                                Err(_) => Err(digits),
-                            })
-                            .or_else(|digits| match digits.parse::<f64>() {
-                                Ok(num) => Ok(StaticNode::from(num)),
-                                Err(_) => Err(digits),
-                            })
-                            .unwrap();
-                        *json = Value::Static(node);
-                    }
+            let Some(key) = json_path_key(&quoted[..len]) else {
+                return Err(LineError::syntax(quoted, "valid escapes"));
+            };
+            let room = enter_key(json, rest, key, on_conflict)?;
+            rest = &quoted[len + 2..];
+            room
+        } else {
+            let Some(end) = after_bracket.find(']') else {
+                return Err(LineError::syntax(&line[line.len()..], "`]`"));
+            };
+            let Ok(index) = after_bracket[..end].parse::<usize>() else {
+                return Err(LineError::syntax(after_bracket, "an array index or `'`"));
+            };
+            let room = enter_index(json, rest, index, on_conflict, mode)?;
+            rest = &after_bracket[end + 1..];
+            room
+        };
+        let Some(room) = room else {
+            return Ok(());
+        };
+        json = room;
+    }
+    match rest.as_bytes().first() {
+        Some(b'\t') => assign_record(json, rest, on_conflict, mode),
+        Some(b' ') => assign(json, rest, on_conflict, mode),
+        _ => Err(LineError::syntax(rest, "`[`, ` = ` or a tab")),
+    }
+}
+
+/// Adds a line of [`LineFormat::Json`](crate::gron::LineFormat::Json), like
+/// `[["a",0],1]`. Whitespace is allowed between the elements.
+fn add_json_line<'a>(
+    mut json: &mut Value<'a>,
+    line: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    let Some(mut rest) = line
+        .strip_prefix('[')
+        .and_then(|rest| rest.trim_start().strip_prefix('['))
+    else {
+        return Err(LineError::syntax(line, "`[[`"));
+    };
+    rest = rest.trim_start();
+    if let Some(after) = rest.strip_prefix(']') {
+        rest = after;
+    } else {
+        loop {
+            let room = if let Some(quoted) = rest.strip_prefix('"') {
+                let Some(len) = quoted_len(quoted.as_bytes(), b'"') else {
+                    return Err(LineError::syntax(&line[line.len()..], "closing `\"`"));
+                };
+                let room = enter_key(json, rest, Cow::Borrowed(&quoted[..len]), on_conflict)?;
+                rest = &quoted[len + 1..];
+                room
+            } else {
+                let end = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let Ok(index) = rest[..end].parse::<usize>() else {
+                    return Err(LineError::syntax(rest, "a key or an array index"));
+                };
+                let room = enter_index(json, rest, index, on_conflict, mode)?;
+                rest = &rest[end..];
+                room
+            };
+            let Some(room) = room else {
+                return Ok(());
+            };
+            json = room;
+            rest = rest.trim_start();
+            match rest.as_bytes().first() {
+                Some(b',') => rest = rest[1..].trim_start(),
+                Some(b']') => {
+                    rest = &rest[1..];
+                    break;
                 }
-                return;
+                _ => return Err(LineError::syntax(rest, "`,` or `]`")),
+            }
+        }
+    }
+    let Some(item) = rest.trim_start().strip_prefix(',') else {
+        return Err(LineError::syntax(rest, "`,`"));
+    };
+    let Some(item) = item.trim_end().strip_suffix(']') else {
+        return Err(LineError::syntax(&line[line.len()..], "`]`"));
+    };
+    assign_item(json, rest, item.trim(), on_conflict, mode)
+}
+
+/// Converts the contents of a single-quoted name in a JSONPath to an escaped
+/// key, or returns `None` for an invalid escape.
+fn json_path_key(name: &str) -> Option<Cow<'_, str>> {
+    // Most escapes are the same as in json, so most keys can be borrowed.
+    if !name.contains('"') && !["\\'", "\\u", "\\/"].iter().any(|e| name.contains(e)) {
+        return Some(Cow::Borrowed(name));
+    }
+    let mut key = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            key.push(c);
+            continue;
+        }
+        key.push(match chars.next()? {
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let hex = chars.as_str().get(..4)?;
+                let c = char::from_u32(u32::from_str_radix(hex, 16).ok()?)?;
+                chars = chars.as_str()[4..].chars();
+                c
+            }
+            c @ ('\'' | '"' | '/' | '\\') => c,
+            _ => return None,
+        });
+    }
+    Some(Cow::Owned(crate::gron::escape(&key).into_owned()))
+}
+
+/// Goes into `key` of the object at `json`, which is made an object if vacant.
+/// Returns `None` if the line should be skipped.
+fn enter_key<'a, 'b>(
+    json: &'b mut Value<'a>,
+    at: &'a str,
+    key: Cow<'a, str>,
+    on_conflict: Conflict,
+) -> Result<Option<&'b mut Value<'a>>, LineError<'a>> {
+    let Some(json) = make_room(json, at, "an object", on_conflict)? else {
+        return Ok(None);
+    };
+    if is_hole(json) {
+        *json = Value::Object(Box::default());
+    }
+    let Value::Object(o) = json else {
+        unreachable!()
+    };
+    Ok(Some(o.entry(key).or_insert_with(hole)))
+}
+
+/// Goes into element `index` of the array at `json`, like [`enter_key`].
+/// Fails once the holes filled in before assigned indices would exceed the
+/// budget of `mode.holes`.
+fn enter_index<'a, 'b>(
+    json: &'b mut Value<'a>,
+    at: &'a str,
+    index: usize,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<Option<&'b mut Value<'a>>, LineError<'a>> {
+    let Some(json) = make_room(json, at, "an array", on_conflict)? else {
+        return Ok(None);
+    };
+    if is_hole(json) {
+        *json = Value::Array(Vec::new());
+    }
+    let Value::Array(v) = json else {
+        unreachable!()
+    };
+    if v.len() <= index {
+        let holes = index - v.len();
+        let within_budget = mode
+            .holes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |left| {
+                left.checked_sub(holes)
+            })
+            .is_ok();
+        match index.checked_add(1) {
+            Some(len) if within_budget => v.resize_with(len, hole),
+            _ => {
+                return Err(LineError::syntax(
+                    at,
+                    "an array index leaving fewer elements unassigned",
+                ))
             }
-            _ => panic!("invalid"),
         }
     }
+    Ok(Some(&mut v[index]))
+}
+
+/// Assigns the ` = item;` in `line` to `json`. With `mode.patch`, the item
+/// may be `undefined` to delete `json`.
+fn assign<'a>(
+    json: &mut Value<'a>,
+    line: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    let Some(item) = line.strip_prefix(" = ") else {
+        return Err(LineError::syntax(line, "` = `"));
+    };
+    let Some(item) = item.strip_suffix(';') else {
+        return Err(LineError::syntax(&item[item.len()..], "`;`"));
+    };
+    assign_item(json, line, item, on_conflict, mode)
+}
+
+/// Assigns `item` from `line` to `json`, like [`assign`].
+fn assign_item<'a>(
+    json: &mut Value<'a>,
+    line: &'a str,
+    item: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    if mode.patch && item == "undefined" {
+        *json = Value::String(Cow::Borrowed(DELETED));
+        return Ok(());
+    }
+    let new = match item {
+        "{}" => Value::Object(Box::default()),
+        "[]" => Value::Array(Vec::new()),
+        _ if item.len() >= 2 && item.starts_with('"') && item.ends_with('"') => {
+            Value::String(Cow::Borrowed(&item[1..item.len() - 1]))
+        }
+        "null" => Value::Static(StaticNode::Null),
+        "true" => Value::Static(StaticNode::Bool(true)),
+        "false" => Value::Static(StaticNode::Bool(false)),
+        _ => number(item, mode).ok_or_else(|| {
+            LineError::syntax(
+                item,
+                "`{}`, `[]`, a string, a number, `true`, `false` or `null`",
+            )
+        })?,
+    };
+    assign_value(json, line, new, on_conflict)
+}
+
+/// Assigns the `<TAB>type<TAB>value` of a record in `fields` to `json`.
+fn assign_record<'a>(
+    json: &mut Value<'a>,
+    fields: &'a str,
+    on_conflict: Conflict,
+    mode: Mode<'_>,
+) -> Result<(), LineError<'a>> {
+    let Some((kind, value)) = fields[1..].split_once('\t') else {
+        return Err(LineError::syntax(&fields[fields.len()..], "a tab"));
+    };
+    let new = match (kind, value) {
+        ("string", _) => Value::String(Cow::Borrowed(value)),
+        ("number", _) => match number(value, mode) {
+            Some(num) => num,
+            None => return Err(LineError::syntax(value, "a number")),
+        },
+        ("boolean", "true") => Value::Static(StaticNode::Bool(true)),
+        ("boolean", "false") => Value::Static(StaticNode::Bool(false)),
+        ("boolean", _) => return Err(LineError::syntax(value, "`true` or `false`")),
+        ("null", "null") => Value::Static(StaticNode::Null),
+        ("null", _) => return Err(LineError::syntax(value, "`null`")),
+        ("object", "{}") => Value::Object(Box::default()),
+        ("object", _) => return Err(LineError::syntax(value, "`{}`")),
+        ("array", "[]") => Value::Array(Vec::new()),
+        ("array", _) => return Err(LineError::syntax(value, "`[]`")),
+        _ => {
+            return Err(LineError::syntax(
+                &fields[1..],
+                "`string`, `number`, `boolean`, `null`, `object` or `array`",
+            ))
+        }
+    };
+    assign_value(json, fields, new, on_conflict)
 }
 
-fn merge_json<'a>(j1: Value<'a>, j2: Value<'a>) -> Value<'a> {
-    match (j1, j2) {
-        (Value::Object(mut o1), Value::Object(mut o2)) => Value::Object({
-            if o2.len() > o1.len() {
-                mem::swap(&mut o1, &mut o2);
+/// Assigns `new` from `line` to `json`, settling any conflict.
+fn assign_value<'a>(
+    json: &mut Value<'a>,
+    line: &'a str,
+    new: Value<'a>,
+    on_conflict: Conflict,
+) -> Result<(), LineError<'a>> {
+    let found = current(json);
+    let json = match (found, &new) {
+        _ if is_hole(found) || is_deleted(found) => current_mut(json),
+        // Declaring a container again keeps what has been assigned into it.
+        (Value::Object(_), Value::Object(_)) | (Value::Array(_), Value::Array(_)) => return Ok(()),
+        (Value::Static(_) | Value::String(_), _) if same_scalar(found, &new) => return Ok(()),
+        _ => {
+            let err = LineError::conflict(line, type_name(&new), found);
+            match settle(json, on_conflict, err)? {
+                Some(vacant) => vacant,
+                None => return Ok(()),
             }
+        }
+    };
+    *json = new;
+    Ok(())
+}
+
+/// Parses a number, keeping it as written with `mode.lossless_numbers`.
+fn number<'a>(digits: &str, mode: Mode<'_>) -> Option<Value<'a>> {
+    if mode.lossless_numbers {
+        crate::gron::is_json_number(digits.as_bytes())
+            .then(|| Value::String(Cow::Owned(digits.to_owned())))
+    } else {
+        parse_number(digits).map(Value::Static)
+    }
+}
+
+pub(crate) fn parse_number(digits: &str) -> Option<StaticNode> {
+    Err(digits)
+        .or_else(|digits| match digits.parse::<u64>() {
+            Ok(num) => Ok(StaticNode::from(num)),
+            Err(_) => Err(digits),
+        })
+        .or_else(|digits| match digits.parse::<i64>() {
+            Ok(num) => Ok(StaticNode::from(num)),
+            Err(_) => Err(digits),
+        })
+        .or_else(|digits| match digits.parse::<f64>() {
+            Ok(num) => Ok(StaticNode::from(num)),
+            Err(_) => Err(digits),
+        })
+        .ok()
+}
+
+/// The length of the escaped string at the start of `bytes`, which is
+/// terminated by the first unescaped `quote`.
+fn quoted_len(bytes: &[u8], quote: u8) -> Option<usize> {
+    let mut i = 0;
+    loop {
+        match *bytes.get(i)? {
+            b'\\' => i += 2,
+            b if b == quote => return Some(i),
+            _ => i += 1,
+        }
+    }
+}
+
+/// Merges the values from two consecutive chunks of lines, or returns `None`
+/// if they assign different things to the same path.
+fn merge_json<'a>(j1: Value<'a>, j2: Value<'a>) -> Option<Value<'a>> {
+    Some(match (j1, j2) {
+        (hole, any) if is_hole(&hole) => any,
+        (any, hole) if is_hole(&hole) => any,
+        (Value::Object(mut o1), Value::Object(o2)) => Value::Object({
+            // Keys first assigned by the earlier chunk stay first.
             for (k, v) in *o2 {
-                o1.insert_nocheck(k, v);
+                if let Some(existing) = o1.get_mut(k.as_ref()) {
+                    let prev = mem::replace(existing, hole());
+                    *existing = merge_json(prev, v)?;
+                } else {
+                    o1.insert(k, v);
+                }
             }
             o1
         }),
         (Value::Array(mut a1), Value::Array(mut a2)) => Value::Array({
-            a1.append(&mut a2);
+            let swapped = a2.len() > a1.len();
+            if swapped {
+                mem::swap(&mut a1, &mut a2);
+            }
+            for (slot, item) in a1.iter_mut().zip(a2) {
+                let prev = mem::replace(slot, hole());
+                *slot = if swapped {
+                    merge_json(item, prev)?
+                } else {
+                    merge_json(prev, item)?
+                };
+            }
             a1
         }),
-        (Value::Static(StaticNode::Null), any) => any,
-        (any, Value::Static(StaticNode::Null)) => any,
-        (a, b) => panic!("invalid gronlines; merging {a} and {b}"),
+        (s1 @ (Value::Static(_) | Value::String(_)), s2) if same_scalar(&s1, &s2) => s1,
+        _ => return None,
+    })
+}
+
+/// Converts the document parsed for [`Ungron::patch_to_writer`], owning the
+/// strings at the sorted addresses `numbers` that are numbers kept as written.
+/// Unlike the borrowed value of simd-json, the tape has keys in document order.
+fn from_tape<'a>(nodes: &mut impl Iterator<Item = Node<'a>>, numbers: &[usize]) -> Value<'a> {
+    match nodes.next() {
+        Some(Node::Static(val)) => Value::Static(val),
+        Some(Node::String(s)) if numbers.binary_search(&(s.as_ptr() as usize)).is_ok() => {
+            Value::String(Cow::Owned(s.to_owned()))
+        }
+        Some(Node::String(s)) => Value::String(Cow::Borrowed(s)),
+        Some(Node::Array { len, .. }) => {
+            Value::Array((0..len).map(|_| from_tape(nodes, numbers)).collect())
+        }
+        Some(Node::Object { len, .. }) => {
+            let mut object = Object::with_capacity_and_hasher(len, ObjectHasher::default());
+            for _ in 0..len {
+                let Some(Node::String(key)) = nodes.next() else {
+                    unreachable!("object keys are strings")
+                };
+                let value = from_tape(nodes, numbers);
+                object.insert(Cow::Borrowed(key), value);
+            }
+            Value::Object(Box::new(object))
+        }
+        None => unreachable!("the tape ends within a value"),
+    }
+}
+
+/// Removes the values deleted by [`Ungron::patch_to_writer`].
+fn remove_deleted(json: &mut Value<'_>) {
+    match json {
+        Value::Array(array) => {
+            array.retain(|item| !is_deleted(item));
+            array.iter_mut().for_each(remove_deleted);
+        }
+        Value::Object(object) => {
+            object.retain(|_, value| !is_deleted(value));
+            object.values_mut().for_each(remove_deleted);
+        }
+        _ => {}
+    }
+}
+
+/// Replaces what is left of the markers used while adding lines: holes become
+/// null or are removed with `compact_arrays`, and arrays of conflicting values
+/// lose their marker.
+fn finish(json: &mut Value<'_>, compact_arrays: bool) {
+    match json {
+        _ if is_hole(json) => *json = Value::Static(StaticNode::Null),
+        Value::Array(array) => {
+            if array.first().is_some_and(is_merge_marker) {
+                array.remove(0);
+            }
+            if compact_arrays {
+                array.retain(|item| !is_hole(item));
+            }
+            array
+                .iter_mut()
+                .for_each(|item| finish(item, compact_arrays));
+        }
+        Value::Object(object) => object
+            .values_mut()
+            .for_each(|value| finish(value, compact_arrays)),
+        _ => {}
     }
 }
 
@@ -133,15 +1422,378 @@ fn merge_json<'a>(j1: Value<'a>, j2: Value<'a>) -> Value<'a> {
 mod test {
     use super::*;
 
+    fn ungron_lines<'a>(lines: &[&'a str], on_conflict: Conflict) -> Value<'a> {
+        let mut json = hole();
+        for line in lines {
+            assert!(add_line_to_json(
+                &mut json,
+                line,
+                on_conflict,
+                Mode::new(&AtomicUsize::new(MIN_HOLES), false)
+            )
+            .is_ok());
+        }
+        json
+    }
+
     #[test]
     fn test_add_line_to_json() {
         assert_eq!(
-            add_line_to_json(Value::Static(StaticNode::Null), r#"json = "abc";"#),
+            ungron_lines(&[r#"json = "abc";"#], Conflict::Error),
             Value::String(Cow::Borrowed("abc"))
         );
         assert_eq!(
-            add_line_to_json(Value::Static(StaticNode::Null), r#"json = "abc\n\r";"#),
+            ungron_lines(&[r#"json = "abc\n\r";"#], Conflict::Error),
             Value::String(Cow::Borrowed(r#"abc\n\r"#))
         );
     }
+
+    #[test]
+    fn test_records() {
+        assert_eq!(
+            ungron_lines(
+                &[
+                    "json.a b\tarray\t[]",
+                    "json.a b[1]\tstring\tx y\\t",
+                    "json[\"c\"]\tnumber\t1.5"
+                ],
+                Conflict::Error
+            ),
+            ungron_lines(
+                &["json[\"a b\"][1] = \"x y\\t\";", "json.c = 1.5;"],
+                Conflict::Error
+            ),
+        );
+        let mut json = hole();
+        for (line, column) in [
+            ("json\tboolean\t1", 14),
+            ("json\tint\t1", 6),
+            ("json\tnull", 10),
+        ] {
+            let err = add_line_to_json(
+                &mut json,
+                line,
+                Conflict::Error,
+                Mode::new(&AtomicUsize::new(MIN_HOLES), false),
+            )
+            .unwrap_err();
+            assert_eq!(
+                err.locate(line).to_string().split(':').next().unwrap(),
+                format!("line 1, column {column}")
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_syntaxes() {
+        let expected = ungron_lines(
+            &[
+                r#"json["a/b"] = [];"#,
+                r#"json["a/b"][0].c = "x";"#,
+                r#"json["0"]["1"] = "'\n";"#,
+            ],
+            Conflict::Error,
+        );
+        assert_eq!(
+            ungron_lines(
+                &[
+                    " = {};",
+                    "/a~1b = [];",
+                    r#"/a~1b/0/c = "x";"#,
+                    "/0 = {};",
+                    r#"/0/1 = "'\n";"#,
+                ],
+                Conflict::Error
+            ),
+            expected,
+        );
+        assert_eq!(
+            ungron_lines(
+                &["$['a/b'][0]['c']\tstring\tx", r#"$['0']['1'] = "'\n";"#,],
+                Conflict::Error
+            ),
+            expected,
+        );
+        // Digits are indices where no object is declared, and keys with
+        // ` = ` end before the real assignment.
+        assert_eq!(
+            ungron_lines(&["/0/a = 1 = 2;"], Conflict::Error),
+            ungron_lines(&[r#"json[0]["a = 1"] = 2;"#], Conflict::Error),
+        );
+        assert_eq!(
+            ungron_lines(&[r"$['\'\u0001'] = 1;"], Conflict::Error),
+            ungron_lines(&[r#"json["'\u0001"] = 1;"#], Conflict::Error),
+        );
+
+        // Unescaped keys keep their place among the others.
+        let ungron = |lines: &str| {
+            let mut output = Vec::new();
+            Ungron::new()
+                .indent(Indent::Compact)
+                .to_writer(lines.as_bytes(), &mut output)
+                .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+        for lines in [
+            "/z = 1;\n/a~1b = 2;\n/a~0b = 3;\n/c = 4;\n",
+            "$['z'] = 1;\n$['a/b'] = 2;\n$['a~b'] = 3;\n$['c'] = 4;\n",
+            "$['z'] = 1;\n$['a\\u002fb'] = 2;\n$['a\\u007eb'] = 3;\n$['c'] = 4;\n",
+        ] {
+            assert_eq!(ungron(lines), "{\"z\":1,\"a/b\":2,\"a~b\":3,\"c\":4}\n");
+        }
+    }
+
+    #[test]
+    fn test_json_lines() {
+        assert_eq!(
+            ungron_lines(&[r#"[["a", 1 ,"b"] , "x"]"#, "[[],{}]"], Conflict::Error),
+            ungron_lines(&[r#"json.a[1].b = "x";"#], Conflict::Error),
+        );
+        let mut json = hole();
+        for (line, column) in [("[[\"a\"],]", 8), ("[[\"a\" 1],2]", 7), ("[[-1],2]", 3)] {
+            let err = add_line_to_json(
+                &mut json,
+                line,
+                Conflict::Error,
+                Mode::new(&AtomicUsize::new(MIN_HOLES), false),
+            )
+            .unwrap_err();
+            let err = err.locate(line).to_string();
+            assert!(
+                err.starts_with(&format!("line 1, column {column}:")),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_array_indices() {
+        let lines = ["json[1] = 1;", "json[0] = 0;", "json[3] = 3;"];
+        let mut json = ungron_lines(&lines, Conflict::Error);
+        finish(&mut json, false);
+        assert_eq!(
+            json,
+            Value::Array(vec![
+                Value::Static(StaticNode::U64(0)),
+                Value::Static(StaticNode::U64(1)),
+                Value::Static(StaticNode::Null),
+                Value::Static(StaticNode::U64(3)),
+            ])
+        );
+
+        let mut json = ungron_lines(&lines, Conflict::Error);
+        finish(&mut json, true);
+        assert_eq!(
+            json,
+            Value::Array(vec![
+                Value::Static(StaticNode::U64(0)),
+                Value::Static(StaticNode::U64(1)),
+                Value::Static(StaticNode::U64(3))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_merge_by_index() {
+        let left = ungron_lines(&["json[1].a = 1;"], Conflict::Error);
+        let right = ungron_lines(&["json[0] = null;", "json[1].b = 2;"], Conflict::Error);
+        let Some(Value::Array(merged)) = merge_json(left, right) else {
+            panic!("expected array")
+        };
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0], Value::Static(StaticNode::Null));
+        let Value::Object(object) = &merged[1] else {
+            panic!("expected object")
+        };
+        assert_eq!(object.len(), 2);
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let lines = [
+            "json.a = 1;",
+            "json.a = 2;",
+            "json.b = {};",
+            "json.b.c = true;",
+            "json.b = \"x\";",
+            "json.b.d = null;",
+            "json.a = 2;",
+        ];
+        let ungron = |on_conflict| {
+            let mut output = Vec::new();
+            Ungron::new()
+                .indent(Indent::Compact)
+                .on_conflict(on_conflict)
+                .to_writer(lines.join("\n").as_bytes(), &mut output)
+                .map(|()| String::from_utf8(output).unwrap())
+        };
+        assert!(matches!(
+            ungron(Conflict::Error),
+            Err(Error::Conflict { line: 2, .. })
+        ));
+        assert_eq!(
+            ungron(Conflict::FirstWins).unwrap(),
+            "{\"a\":1,\"b\":{\"c\":true,\"d\":null}}\n"
+        );
+        assert_eq!(
+            ungron(Conflict::LastWins).unwrap(),
+            "{\"a\":2,\"b\":{\"d\":null}}\n"
+        );
+        assert_eq!(
+            ungron(Conflict::MergeToArray).unwrap(),
+            "{\"a\":[1,2],\"b\":[{\"c\":true},\"x\",{\"d\":null}]}\n"
+        );
+    }
+
+    #[test]
+    fn test_patch() {
+        let patch = |json: &str, lines: &str| {
+            let mut output = Vec::new();
+            Ungron::new()
+                .indent(Indent::Compact)
+                .patch_to_writer(json.as_bytes(), lines.as_bytes(), &mut output)
+                .map(|()| String::from_utf8(output).unwrap())
+        };
+        assert_eq!(
+            patch(
+                r#"{"z":[0,1,2,3],"a":{"b":1}}"#,
+                concat!(
+                    "json.z[1] = undefined;\n",
+                    "json.z[2] = undefined;\n",
+                    "json.z[2] = \"<deleted>\";\n",
+                    "json.a.b = undefined;\n",
+                    "json.a.b.c = 2;\n",
+                    "json.a = {};\n",
+                    "json.y = undefined;\n",
+                    "json.x = [];\n",
+                ),
+            )
+            .unwrap(),
+            "{\"z\":[0,\"<deleted>\",3],\"a\":{\"b\":{\"c\":2}},\"x\":[]}\n"
+        );
+        // Declaring a container keeps its contents, so lines with their
+        // ancestors patch only what they assign.
+        assert_eq!(
+            patch(
+                r#"{"spec":{"a":1},"items":[1,2]}"#,
+                concat!(
+                    "json = {};\n",
+                    "json.spec = {};\n",
+                    "json.items = [];\n",
+                    "json.items[1] = 3;\n",
+                    "json.spec.b = 2;\n",
+                ),
+            )
+            .unwrap(),
+            "{\"spec\":{\"a\":1,\"b\":2},\"items\":[1,3]}\n"
+        );
+        assert_eq!(
+            patch(
+                r#"{"spec":{"a":1},"items":[1,2]}"#,
+                "json.spec = undefined;\njson.spec = {};\njson.items = undefined;\njson.items = [];\n",
+            )
+            .unwrap(),
+            "{\"spec\":{},\"items\":[]}\n"
+        );
+        assert!(matches!(
+            patch("{}", "json.a = 1;\njson = undefined;\n"),
+            Err(Error::Syntax { line: 2, .. })
+        ));
+        assert!(matches!(patch("{", "json.a = 1;\n"), Err(Error::Json(_))));
+    }
+
+    #[test]
+    fn test_lossless_numbers() {
+        let ungron = |lines: &str| {
+            let mut output = Vec::new();
+            Ungron::new()
+                .indent(Indent::Compact)
+                .lossless_numbers(true)
+                .to_writer(lines.as_bytes(), &mut output)
+                .map(|()| String::from_utf8(output).unwrap())
+        };
+        assert_eq!(
+            ungron("json.a = 1.0;\njson.a = 1.0;\njson.b\tnumber\t2e5").unwrap(),
+            "{\"a\":1.0,\"b\":2e5}\n"
+        );
+        // A number is not the string of its literal.
+        assert!(matches!(
+            ungron("json.a = 1;\njson.a = \"1\";"),
+            Err(Error::Conflict {
+                line: 2,
+                expected: "a string",
+                found: "a number",
+                ..
+            })
+        ));
+        assert!(matches!(
+            ungron("json.a = 01;"),
+            Err(Error::Syntax { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        let process = |data: &str, lenient| {
+            Ungron::new()
+                .lenient(lenient)
+                .to_writer(data.as_bytes(), std::io::sink())
+        };
+        assert!(matches!(
+            process("json = {};\njson.a = 1;\njson.a.b = 2;\n", false),
+            Err(Error::Conflict {
+                line: 3,
+                column: 7,
+                expected: "an object",
+                found: "a number",
+            })
+        ));
+        assert!(matches!(
+            process("json = {};\n// comment\n", false),
+            Err(Error::Syntax {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            process("json = [];\njson[0] = \"trunc", false),
+            Err(Error::Syntax {
+                line: 2,
+                column: 17,
+                expected: "`;`",
+            })
+        ));
+        assert!(matches!(
+            process("json.a = 1;\njson.b = 2\njson.c = 3;\n", true),
+            Ok(())
+        ));
+        // Holes are not filled in up to an index far past the size of the input.
+        for line in ["json[18446744073709551615] = 1;", "json[999999999999] = 1;"] {
+            for compact_arrays in [false, true] {
+                assert!(matches!(
+                    Ungron::new()
+                        .compact_arrays(compact_arrays)
+                        .to_writer(line.as_bytes(), std::io::sink()),
+                    Err(Error::Syntax {
+                        line: 1,
+                        column: 5,
+                        expected: "an array index leaving fewer elements unassigned",
+                    })
+                ));
+            }
+        }
+        // Nor across many arrays that each stay within it.
+        let lines: String = (0..8)
+            .map(|i| format!("json[{i}][1048575] = 1;\n"))
+            .collect();
+        assert!(matches!(
+            process(&lines, false),
+            Err(Error::Syntax {
+                line: 2,
+                column: 8,
+                expected: "an array index leaving fewer elements unassigned",
+            })
+        ));
+    }
 }
@@ -27,20 +27,30 @@
 
         patched-simd-json-src = let
           gzipped = pkgs.fetchurl {
-            url = "https://crates.io/api/v1/crates/simd-json/0.10.3/download";
-            sha256 = "sha256-o9CBXn/w8fBeCdSwKfhtijMPCrFbNbKHNvN1gyX1nhQ=";
+            url = "https://crates.io/api/v1/crates/simd-json/0.13.11/download";
+            sha256 = "sha256-oCKKVkRw+Bck4wmWu8KxcXE7N7FSVKZEDH4tVEm5VpE=";
           };
         in pkgs.runCommand "patched-simd-json-src" { } ''
           cp ${gzipped} src.tar.gz
           tar xvf src.tar.gz
-          mv simd-json-0.10.3 $out
+          mv simd-json-0.13.11 $out
           cd $out
           patch -s --strip=1 < ${./patches/avx2_deser.patch}
+          patch -s --strip=1 < ${./patches/sse42_deser.patch}
+          patch -s --strip=1 < ${./patches/native_deser.patch}
+          patch -s --strip=1 < ${./patches/neon_deser.patch}
+          patch -s --strip=1 < ${./patches/portable_deser.patch}
+          patch -s --strip=1 < ${./patches/simd128_deser.patch}
           patch -s --strip=1 < ${./patches/charutils.patch}
           patch -s --strip=1 < ${./patches/serde_se_pp.patch}
           ${pkgs.rustfmt}/bin/rustfmt $out/src/charutils.rs
-          ${pkgs.rustfmt}/bin/rustfmt $out/src/avx2/deser.rs
           ${pkgs.rustfmt}/bin/rustfmt $out/src/serde/se/pp.rs
+          ${pkgs.rustfmt}/bin/rustfmt $out/src/impls/avx2/deser.rs
+          ${pkgs.rustfmt}/bin/rustfmt $out/src/impls/sse42/deser.rs
+          ${pkgs.rustfmt}/bin/rustfmt $out/src/impls/native/deser.rs
+          ${pkgs.rustfmt}/bin/rustfmt $out/src/impls/neon/deser.rs
+          ${pkgs.rustfmt}/bin/rustfmt $out/src/impls/portable/deser.rs
+          ${pkgs.rustfmt}/bin/rustfmt $out/src/impls/simd128/deser.rs
         '';
 
         rust = import ./rust.nix {
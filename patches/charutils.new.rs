@@ -22,12 +22,12 @@ const STRUCTURAL_OR_WHITESPACE: [u32; 256] = [
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ];
 
-#[cfg_attr(not(feature = "no-inline"), inline(always))]
+#[cfg_attr(not(feature = "no-inline"), inline)]
 pub fn is_not_structural_or_whitespace(c: u8) -> u32 {
     unsafe { *STRUCTURAL_OR_WHITESPACE_NEGATED.get_kinda_unchecked(c as usize) }
 }
 
-#[cfg_attr(not(feature = "no-inline"), inline(always))]
+#[cfg_attr(not(feature = "no-inline"), inline)]
 pub fn is_structural_or_whitespace(c: u8) -> u32 {
     unsafe { *STRUCTURAL_OR_WHITESPACE.get_kinda_unchecked(c as usize) }
 }
@@ -90,39 +90,39 @@ pub fn codepoint_to_utf8(cp: u32, c: &mut [u8]) -> usize {
                 b'"' => {
                     *c.get_kinda_unchecked_mut(0) = b'\\';
                     *c.get_kinda_unchecked_mut(1) = b'"';
-                    return 2;
-                },
+                    2
+                }
                 b'\\' => {
                     *c.get_kinda_unchecked_mut(0) = b'\\';
                     *c.get_kinda_unchecked_mut(1) = b'\\';
-                    return 2;
-                },
+                    2
+                }
                 0x08 => {
                     *c.get_kinda_unchecked_mut(0) = b'\\';
                     *c.get_kinda_unchecked_mut(1) = b'b';
-                    return 2;
-                },
+                    2
+                }
                 0x0C => {
                     *c.get_kinda_unchecked_mut(0) = b'\\';
                     *c.get_kinda_unchecked_mut(1) = b'f';
-                    return 2;
-                },
+                    2
+                }
                 b'\n' => {
                     *c.get_kinda_unchecked_mut(0) = b'\\';
                     *c.get_kinda_unchecked_mut(1) = b'n';
-                    return 2;
-                },
+                    2
+                }
                 b'\r' => {
                     *c.get_kinda_unchecked_mut(0) = b'\\';
                     *c.get_kinda_unchecked_mut(1) = b'r';
-                    return 2;
-                },
+                    2
+                }
                 b'\t' => {
                     *c.get_kinda_unchecked_mut(0) = b'\\';
                     *c.get_kinda_unchecked_mut(1) = b't';
-                    return 2;
-                },
-                control if control <= 0x1F || (control >= 0x7F && control <= 0x9F)  => {
+                    2
+                }
+                control if control <= 0x1F || (0x7F..=0x9F).contains(&control) => {
                     fn nibble_to_digit(v: u8) -> u8 {
                         if v <= 9 {
                             b'0' + v
@@ -136,35 +136,36 @@ pub fn codepoint_to_utf8(cp: u32, c: &mut [u8]) -> usize {
                     *c.get_kinda_unchecked_mut(3) = b'0';
                     *c.get_kinda_unchecked_mut(4) = nibble_to_digit(control >> 4);
                     *c.get_kinda_unchecked_mut(5) = nibble_to_digit(control & 0x0F);
-                    return 6;
+                    6
                 }
                 _ => {
                     *c.get_kinda_unchecked_mut(0) = cp as u8;
-                    return 1; // ascii
-                },
+                    1 // ascii
+                }
             }
-        }
-        if cp <= 0x7FF {
+        } else if cp <= 0x7FF {
             *c.get_kinda_unchecked_mut(0) = ((cp >> 6) + 192) as u8;
             *c.get_kinda_unchecked_mut(1) = ((cp & 63) + 128) as u8;
-            return 2; // universal plane
-                      //  Surrogates are treated elsewhere...
-                      //} //else if (0xd800 <= cp && cp <= 0xdfff) {
-                      //  return 0; // surrogates // could put assert here
+            2
+            // universal plane
+            //  Surrogates are treated elsewhere...
+            //} //else if (0xd800 <= cp && cp <= 0xdfff) {
+            //  return 0; // surrogates // could put assert here
         } else if cp <= 0xFFFF {
             *c.get_kinda_unchecked_mut(0) = ((cp >> 12) + 224) as u8;
             *c.get_kinda_unchecked_mut(1) = (((cp >> 6) & 63) + 128) as u8;
             *c.get_kinda_unchecked_mut(2) = ((cp & 63) + 128) as u8;
-            return 3;
+            3
         } else if cp <= 0x0010_FFFF {
             // if you know you have a valid code point, this is not needed
             *c.get_kinda_unchecked_mut(0) = ((cp >> 18) + 240) as u8;
             *c.get_kinda_unchecked_mut(1) = (((cp >> 12) & 63) + 128) as u8;
             *c.get_kinda_unchecked_mut(2) = (((cp >> 6) & 63) + 128) as u8;
             *c.get_kinda_unchecked_mut(3) = ((cp & 63) + 128) as u8;
-            return 4;
+            4
+        } else {
+            // will return 0 when the code point was too large.
+            0
         }
     }
-    // will return 0 when the code point was too large.
-    0
 }
@@ -1,10 +1,11 @@
-use crate::{serde_ext, stry, Error, ErrorType};
+use crate::{stry, Error, ErrorType};
 use serde_ext::ser;
 use std::io::Write;
-use std::result::Result;
 use std::str;
 use value_trait::generator::BaseGenerator;
 
+use super::key_must_be_a_string;
+
 macro_rules! iomap {
     ($e:expr) => {
         ($e).map_err(|err| Error::generic(ErrorType::Io(err)))
@@ -14,21 +15,21 @@ macro_rules! iomap {
 /// Write a value to a vector
 /// # Errors
 /// when the data can not be written
-#[inline]
+#[cfg_attr(not(feature = "no-inline"), inline)]
 pub fn to_vec_pretty<T>(to: &T) -> crate::Result<Vec<u8>>
 where
     T: ser::Serialize + ?Sized,
 {
     let v = Vec::with_capacity(512);
     let mut s = PrettySerializer::new(v);
-    to.serialize(&mut s).map(|_| s.writer)
+    to.serialize(&mut s).map(|()| s.writer)
 }
 
 /// Write a value to a string
 ///
 /// # Errors
 /// when the data can not be written
-#[inline]
+#[cfg_attr(not(feature = "no-inline"), inline)]
 pub fn to_string_pretty<T>(to: &T) -> crate::Result<String>
 where
     T: ser::Serialize + ?Sized,
@@ -39,7 +40,7 @@ where
 /// Write a value to a string
 /// # Errors
 /// when the data can not be written
-#[inline]
+#[cfg_attr(not(feature = "no-inline"), inline)]
 pub fn to_writer_pretty<T, W>(writer: W, to: &T) -> crate::Result<()>
 where
     T: ser::Serialize + ?Sized,
@@ -63,17 +64,17 @@ where
     W: Write,
 {
     type T = W;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn get_writer(&mut self) -> &mut Self::T {
         &mut self.writer
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn write_min(&mut self, _slice: &[u8], min: u8) -> std::io::Result<()> {
         self.writer.write_all(&[min])
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn new_line(&mut self) -> std::io::Result<()> {
-        self.write_char(b'\n').and_then(|_| match self.dent {
+        self.write_char(b'\n').and_then(|()| match self.dent {
             0 => Ok(()),
             1 => self.get_writer().write_all(b"  "),
             2 => self.get_writer().write_all(b"    "),
@@ -111,7 +112,7 @@ where
 {
     type Ok = ();
     type Error = Error;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: serde_ext::Serialize,
@@ -123,18 +124,19 @@ where
         } = *self;
         if *first {
             *first = false;
-            iomap!(s.new_line()).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.new_line()).and_then(|()| value.serialize(&mut **s))
         } else {
-            iomap!(s.write(b",").and_then(|_| s.new_line())).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.write(b",").and_then(|()| s.new_line()))
+                .and_then(|()| value.serialize(&mut **s))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if self.first {
             Ok(())
         } else {
             self.s.dedent();
-            iomap!(self.s.new_line().and_then(|_| self.s.write(b"]")))
+            iomap!(self.s.new_line().and_then(|()| self.s.write(b"]")))
         }
     }
 }
@@ -145,7 +147,7 @@ where
 {
     type Ok = ();
     type Error = Error;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: serde_ext::Serialize,
@@ -156,18 +158,19 @@ where
         } = *self;
         if *first {
             *first = false;
-            iomap!(s.new_line()).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.new_line()).and_then(|()| value.serialize(&mut **s))
         } else {
-            iomap!(s.write(b",").and_then(|_| s.new_line())).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.write(b",").and_then(|()| s.new_line()))
+                .and_then(|()| value.serialize(&mut **s))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if self.first {
             Ok(())
         } else {
             self.s.dedent();
-            iomap!(self.s.new_line().and_then(|_| self.s.write(b"]")))
+            iomap!(self.s.new_line().and_then(|()| self.s.write(b"]")))
         }
     }
 }
@@ -178,7 +181,7 @@ where
 {
     type Ok = ();
     type Error = Error;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: serde_ext::Serialize,
@@ -189,18 +192,19 @@ where
         } = *self;
         if *first {
             *first = false;
-            iomap!(s.new_line()).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.new_line()).and_then(|()| value.serialize(&mut **s))
         } else {
-            iomap!(s.write(b",").and_then(|_| s.new_line())).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.write(b",").and_then(|()| s.new_line()))
+                .and_then(|()| value.serialize(&mut **s))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if self.first {
             Ok(())
         } else {
             self.s.dedent();
-            iomap!(self.s.new_line().and_then(|_| self.s.write(b"]")))
+            iomap!(self.s.new_line().and_then(|()| self.s.write(b"]")))
         }
     }
 }
@@ -211,7 +215,7 @@ where
 {
     type Ok = ();
     type Error = Error;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: serde_ext::Serialize,
@@ -222,22 +226,246 @@ where
         } = *self;
         if *first {
             *first = false;
-            iomap!(s.new_line()).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.new_line()).and_then(|()| value.serialize(&mut **s))
         } else {
-            iomap!(s.write(b",").and_then(|_| s.new_line())).and_then(|_| value.serialize(&mut **s))
+            iomap!(s.write(b",").and_then(|()| s.new_line()))
+                .and_then(|()| value.serialize(&mut **s))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if self.first {
             Ok(())
         } else {
             self.s.dedent();
-            iomap!(self.s.new_line().and_then(|_| self.s.write(b"}")))
+            iomap!(self.s.new_line().and_then(|()| self.s.write(b"}")))
         }
     }
 }
 
+struct MapKeySerializer<'serializer, W: Write + 'serializer> {
+    s: &'serializer mut PrettySerializer<W>,
+}
+
+impl<'serializer, W> ser::Serializer for MapKeySerializer<'serializer, W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    #[cfg_attr(not(feature = "no-inline"), inline)]
+    fn serialize_str(self, value: &str) -> Result<(), Self::Error> {
+        self.s.serialize_str(value)
+    }
+
+    #[cfg_attr(not(feature = "no-inline"), inline)]
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Self::Error> {
+        self.s.serialize_str(variant)
+    }
+
+    #[cfg_attr(not(feature = "no-inline"), inline)]
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + serde_ext::Serialize,
+    {
+        value.serialize(self)
+    }
+
+    type SerializeSeq = ser::Impossible<(), Error>;
+    type SerializeTuple = ser::Impossible<(), Error>;
+    type SerializeTupleStruct = ser::Impossible<(), Error>;
+    type SerializeTupleVariant = ser::Impossible<(), Error>;
+    type SerializeMap = ser::Impossible<(), Error>;
+    type SerializeStruct = ser::Impossible<(), Error>;
+    type SerializeStructVariant = ser::Impossible<(), Error>;
+
+    fn serialize_bool(self, _value: bool) -> Result<(), Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        iomap!(self
+            .s
+            .write_char(b'"')
+            .and_then(|()| self.s.write_int(v))
+            .and_then(|()| self.s.write_char(b'"')))
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.s.serialize_str(&v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_some<T: ?Sized>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_ext::Serialize,
+    {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_newtype_variant<T: ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: serde_ext::Serialize,
+    {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(key_must_be_a_string())
+    }
+}
+
 struct SerializeMap<'serializer, W: Write + 'serializer> {
     s: &'serializer mut PrettySerializer<W>,
     first: bool,
@@ -249,7 +477,7 @@ where
 {
     type Ok = ();
     type Error = Error;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_key<T: ?Sized>(&mut self, key: &T) -> Result<(), Self::Error>
     where
         T: serde_ext::Serialize,
@@ -263,15 +491,15 @@ where
         if *first {
             *first = false;
             iomap!(s.new_line())
-                .and_then(|_| key.serialize(&mut **s))
-                .and_then(|_| iomap!(s.write(b": ")))
+                .and_then(|()| key.serialize(MapKeySerializer { s: &mut **s }))
+                .and_then(|()| iomap!(s.write(b": ")))
         } else {
-            iomap!(s.write(b",").and_then(|_| s.new_line()))
-                .and_then(|_| key.serialize(&mut **s))
-                .and_then(|_| iomap!(s.write(b": ")))
+            iomap!(s.write(b",").and_then(|()| s.new_line()))
+                .and_then(|()| key.serialize(MapKeySerializer { s: &mut **s }))
+                .and_then(|()| iomap!(s.write(b": ")))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_value<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: serde_ext::Serialize,
@@ -279,13 +507,13 @@ where
         let SerializeMap { ref mut s, .. } = *self;
         value.serialize(&mut **s)
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if self.first {
             Ok(())
         } else {
             self.s.dedent();
-            iomap!(self.s.new_line().and_then(|_| self.s.write(b"}")))
+            iomap!(self.s.new_line().and_then(|()| self.s.write(b"}")))
         }
     }
 }
@@ -296,7 +524,7 @@ where
 {
     type Ok = ();
     type Error = Error;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_field<T: ?Sized>(
         &mut self,
         key: &'static str,
@@ -314,24 +542,24 @@ where
             *first = false;
             iomap!(s
                 .new_line()
-                .and_then(|_| s.write_simple_string(key))
-                .and_then(|_| s.write(b": ")))
-            .and_then(|_| value.serialize(&mut **s))
+                .and_then(|()| s.write_simple_string(key))
+                .and_then(|()| s.write(b": ")))
+            .and_then(|()| value.serialize(&mut **s))
         } else {
             iomap!(s
                 .write(b",")
-                .and_then(|_| s.write_simple_string(key))
-                .and_then(|_| s.write(b": ")))
-            .and_then(|_| value.serialize(&mut **s))
+                .and_then(|()| s.write_simple_string(key))
+                .and_then(|()| s.write(b": ")))
+            .and_then(|()| value.serialize(&mut **s))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn end(self) -> Result<Self::Ok, Self::Error> {
         if self.first {
             Ok(())
         } else {
             self.s.dedent();
-            iomap!(self.s.new_line().and_then(|_| self.s.write(b"}")))
+            iomap!(self.s.new_line().and_then(|()| self.s.write(b"}")))
         }
     }
 }
@@ -347,7 +575,7 @@ where
 {
     type Ok = ();
     type Error = Error;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_field<T: ?Sized>(
         &mut self,
         key: &'static str,
@@ -366,27 +594,27 @@ where
             s.indent();
             iomap!(s
                 .new_line()
-                .and_then(|_| s.write_simple_string(key))
-                .and_then(|_| s.write(b": ")))
-            .and_then(|_| value.serialize(&mut **s))
+                .and_then(|()| s.write_simple_string(key))
+                .and_then(|()| s.write(b": ")))
+            .and_then(|()| value.serialize(&mut **s))
         } else {
             iomap!(s
                 .write(b",")
-                .and_then(|_| s.write_simple_string(key))
-                .and_then(|_| s.write(b": ")))
-            .and_then(|_| value.serialize(&mut **s))
+                .and_then(|()| s.write_simple_string(key))
+                .and_then(|()| s.write(b": ")))
+            .and_then(|()| value.serialize(&mut **s))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn end(self) -> Result<Self::Ok, Self::Error> {
         self.s.dedent();
-        iomap!(self.s.new_line().and_then(|_| self.s.write(b"}"))).and_then(move |_| {
+        iomap!(self.s.new_line().and_then(|()| self.s.write(b"}"))).and_then(move |()| {
             if self.first {
                 Ok(())
             } else {
                 self.s.dedent();
 
-                iomap!(self.s.new_line().and_then(|_| self.s.write(b"}")))
+                iomap!(self.s.new_line().and_then(|()| self.s.write(b"}")))
             }
         })
     }
@@ -405,7 +633,7 @@ where
     type SerializeMap = SerializeMap<'writer, W>;
     type SerializeStruct = SerializeMap<'writer, W>;
     type SerializeStructVariant = SerializeStructVariant<'writer, W>;
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
         if v {
             iomap!(self.write(b"true"))
@@ -413,78 +641,78 @@ where
             iomap!(self.write(b"false"))
         }
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_int(v))
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_float(f64::from(v)))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_float(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
         // A char encoded as UTF-8 takes 4 bytes at most.
         // taken from: https://docs.serde.rs/src/serde_json/ser.rs.html#213
         let mut buf = [0; 4];
         iomap!(self.write_simple_string(v.encode_utf8(&mut buf)))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write_string(v))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        iomap!(self.write(b"[").and_then(|_| {
+        iomap!(self.write(b"[").and_then(|()| {
             if let Some((first, rest)) = v.split_first() {
                 self.indent();
-                self.new_line().and_then(|_| {
-                    self.write_int(*first).and_then(|_| {
+                self.new_line().and_then(|()| {
+                    self.write_int(*first).and_then(|()| {
                         for v in rest {
-                            self.write(b",").and_then(|_| self.write_int(*v))?;
+                            self.write(b",").and_then(|()| self.write_int(*v))?;
                         }
                         self.dedent();
-                        self.new_line().and_then(|_| self.write(b"]"))
+                        self.new_line().and_then(|()| self.write(b"]"))
                     })
                 })
             } else {
@@ -492,26 +720,26 @@ where
             }
         }))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
         self.serialize_unit()
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_some<T: ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
         T: serde_ext::Serialize,
     {
         value.serialize(self)
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
         iomap!(self.write(b"null"))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
         self.serialize_unit()
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_unit_variant(
         self,
         _name: &'static str,
@@ -521,7 +749,7 @@ where
         iomap!(self.write_simple_string(variant))
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_newtype_struct<T: ?Sized>(
         self,
         _name: &'static str,
@@ -533,7 +761,7 @@ where
         value.serialize(self)
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_newtype_variant<T: ?Sized>(
         self,
         _name: &'static str,
@@ -546,12 +774,12 @@ where
     {
         iomap!(self
             .write(b"{")
-            .and_then(|_| self.write_simple_string(variant))
-            .and_then(|_| self.write(b": ")))
-        .and_then(|_| value.serialize(&mut *self))
-        .and_then(|_| iomap!(self.write(b"}")))
+            .and_then(|()| self.write_simple_string(variant))
+            .and_then(|()| self.write(b": ")))
+        .and_then(|()| value.serialize(&mut *self))
+        .and_then(|()| iomap!(self.write(b"}")))
     }
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
         if len == Some(0) {
             iomap!(self.write(b"[]"))
@@ -559,18 +787,18 @@ where
             self.indent();
             iomap!(self.write(b"["))
         }
-        .map(move |_| SerializeSeq {
+        .map(move |()| SerializeSeq {
             s: self,
             first: true,
         })
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
         self.serialize_seq(Some(len))
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_tuple_struct(
         self,
         _name: &'static str,
@@ -579,7 +807,7 @@ where
         self.serialize_seq(Some(len))
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_tuple_variant(
         self,
         _name: &'static str,
@@ -590,13 +818,13 @@ where
         self.indent();
         iomap!(self
             .write(b"{")
-            .and_then(|_| self.new_line())
-            .and_then(|_| self.write_simple_string(variant))
-            .and_then(|_| self.write(b": ")))
-        .and_then(move |_| self.serialize_seq(Some(len)))
+            .and_then(|()| self.new_line())
+            .and_then(|()| self.write_simple_string(variant))
+            .and_then(|()| self.write(b": ")))
+        .and_then(move |()| self.serialize_seq(Some(len)))
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
         if len == Some(0) {
             iomap!(self.write(b"{}"))
@@ -604,13 +832,13 @@ where
             self.indent();
             iomap!(self.write(b"{"))
         }
-        .map(move |_| SerializeMap {
+        .map(move |()| SerializeMap {
             s: self,
             first: true,
         })
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_struct(
         self,
         _name: &'static str,
@@ -619,7 +847,7 @@ where
         self.serialize_map(Some(len))
     }
 
-    #[inline]
+    #[cfg_attr(not(feature = "no-inline"), inline)]
     fn serialize_struct_variant(
         self,
         _name: &'static str,
@@ -630,16 +858,16 @@ where
         self.indent();
         iomap!(self
             .write(b"{")
-            .and_then(|_| self.new_line())
-            .and_then(|_| self.write_simple_string(variant))
-            .and_then(|_| self.write(b": ")))
-        .and_then(move |_| {
+            .and_then(|()| self.new_line())
+            .and_then(|()| self.write_simple_string(variant))
+            .and_then(|()| self.write(b": ")))
+        .and_then(move |()| {
             if len == 0 {
                 iomap!(self.write(b"{}"))
             } else {
                 iomap!(self.write(b"{"))
             }
-            .map(move |_| SerializeStructVariant {
+            .map(move |()| SerializeStructVariant {
                 s: self,
                 first: true,
             })
@@ -649,11 +877,50 @@ where
 
 #[cfg(test)]
 mod test {
+    #![allow(clippy::ignored_unit_patterns)]
     use crate::from_slice;
+    use crate::OwnedValue as Value;
     #[cfg(not(target_arch = "wasm32"))]
-    use crate::{OwnedValue as Value, StaticNode};
+    use crate::StaticNode;
     #[cfg(not(target_arch = "wasm32"))]
     use proptest::prelude::*;
+    #[test]
+    fn pretty_print_serde() {
+        #[derive(Clone, Debug, PartialEq, serde::Serialize)]
+        enum Segment {
+            Id { mid: usize },
+        }
+
+        assert_eq!(
+            "{\n  \"Id\": {\n    \"mid\": 0\n  }\n}",
+            crate::to_string_pretty(&Segment::Id { mid: 0 }).expect("to_string_pretty")
+        );
+    }
+
+    #[test]
+    fn numerical_map_serde() {
+        use std::collections::HashMap;
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize)]
+        struct Foo {
+            pub bar: HashMap<i32, i32>,
+        }
+
+        let mut foo = Foo {
+            bar: HashMap::new(),
+        };
+
+        foo.bar.insert(1337, 1337);
+
+        assert_eq!(
+            r#"{
+  "bar": {
+    "1337": 1337
+  }
+}"#,
+            crate::to_string_pretty(&foo).expect("to_string_pretty")
+        );
+    }
 
     #[cfg(not(feature = "128bit"))]
     #[cfg(not(target_arch = "wasm32"))]
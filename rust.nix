@@ -4,9 +4,6 @@ let
     rustVersion = "latest";
     packageFun = import ./Cargo.nix;
 
-    # Enable avx2
-    hostPlatformCpu = "haswell";
-
     packageOverrides = p:
       let
         mkNativeDep = cratename: extra-deps:
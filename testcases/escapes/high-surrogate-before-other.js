@@ -0,0 +1 @@
+json.a = "\ud83d\u0041";
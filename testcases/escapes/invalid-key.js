@@ -0,0 +1 @@
+json["a\u12"].b = 1;
@@ -0,0 +1 @@
+json.a = "\ude00";
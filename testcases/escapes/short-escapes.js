@@ -0,0 +1 @@
+json.a = "\"\\\/\b\f\n\r\t";
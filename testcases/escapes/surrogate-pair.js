@@ -0,0 +1 @@
+json["\ud83d\ude00"] = "\ud83d\ude00\u00e9";
@@ -0,0 +1 @@
+json.a	string	x\
@@ -0,0 +1,24 @@
+json = {};
+json.a = "\u0000\u0001\u0002\u0003\u0004\u0005\u0006\u0007";
+json.b = "\b\t\n\u000B\f\r\u000E\u000F";
+json.c = "\u0010\u0011\u0012\u0013\u0014\u0015\u0016\u0017";
+json.d = "\u0018\u0019\u001A\u001B\u001C\u001D\u001E\u001F";
+json.e = " !\"#$%&'()*+,-./0123456789:;<=>?@";
+json.f = "ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+json.g = "\u007F\u0080\u0081\u0082\u0083\u0084\u0085\u0086";
+json.h = "\u0087\u0088\u0089\u008A\u008B\u008C\u008D\u008E\u008F";
+json.i = "\u0090\u0091\u0092\u0093\u0094\u0095\u0096\u0097";
+json.j = "\u0098\u0099\u009A\u009B\u009C\u009D\u009E\u009F";
+json.k = " ¡¢£¤¥¦§";
+json.l = "¨©ª«¬­®¯";
+json.m = "°±²³´µ¶·";
+json.n = "¸¹º»¼½¾¿";
+json.o = "ÀÁÂÃÄÅÆÇ";
+json.p = "ÈÉÊËÌÍÎÏ";
+json.q = "ÐÑÒÓÔÕÖ×";
+json.r = "ØÙÚÛÜÝÞß";
+json.s = "àáâãäåæç";
+json.t = "èéêëìíîï";
+json.u = "ðñòóôõö÷";
+json.v = "øùúûüýþÿ";
+json.w = "🙈🙉🙊";
@@ -0,0 +1,26 @@
+json = {};
+json.ab = {};
+json.ab.a = 12;
+json.ab["a b"] = 11;
+json.ab["a!"] = 14;
+json.ab["a\tb"] = 13;
+json.ab["a\u0000"] = 15;
+json.json = {};
+json.json["'"] = 4;
+json.json["["] = 3;
+json.json["\""] = 5;
+json.json["\\"] = 6;
+json.json["]"] = 2;
+json.json["a[0]"] = 1;
+json[";"] = 9;
+json["="] = 8;
+json["a b"] = {};
+json["a b"][""] = [];
+json["a b"][""][0] = [];
+json["a b"][""][1] = {};
+json["a.b[0] = 1;"] = 7;
+json["a\"b"] = {};
+json["a\"b"]["c d"] = {};
+json["a\"b"]["c d"].e = [];
+json["a\"b"]["c d"].e[0] = {};
+json["a\"b"]["c d"].e[0]["f g"] = 10;
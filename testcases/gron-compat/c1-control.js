@@ -0,0 +1,2 @@
+json = {};
+json.a = "\u0081\u008F";
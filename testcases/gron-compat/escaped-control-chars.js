@@ -0,0 +1,2 @@
+json = {};
+json.a = "\b\t\n\f\r";
@@ -0,0 +1,18 @@
+json = {};
+json.astral = "😀𐀀􏿿";
+json.c0 = "\u0001\u0007\u000B\u000E\u001B\u001F";
+json.c1 = "\u0080\u0085\u008A\u009B\u009F";
+json.del = "\u007F";
+json.html = "<>&<>&";
+json.nul = "\u0000";
+json.separators = "\u2028\u2029";
+json.short = "\"\\/\b\f\n\r\t";
+json["/"] = "slash key";
+json["\b\f\n\r\t"] = "short keys";
+json["\u0001"] = "control key";
+json["\u007F"] = "del key";
+json["\u0085"] = "c1 key";
+json["\u2028"] = "separator key";
+json["after c1"] = " ­ÿ";
+json["not separators"] = "‧‪​﻿";
+json["raw c1"] = "\u0085\u009F";
@@ -0,0 +1,26 @@
+json = {};
+json.$ = 4;
+json.$0 = 7;
+json.A = 2;
+json.A_$9 = 6;
+json.Ab = 25;
+json._ = 3;
+json.__proto__ = 8;
+json.a = 1;
+json.a1 = 5;
+json.aB = 24;
+json.ab = 23;
+json[" a"] = 14;
+json[""] = 22;
+json["%"] = 21;
+json["1"] = 9;
+json["1a"] = 10;
+json["9_"] = 11;
+json["a "] = 15;
+json["a b"] = 13;
+json["a#"] = 20;
+json["a+b"] = 19;
+json["a-b"] = 12;
+json["a.b"] = 16;
+json["a:b"] = 17;
+json["a@b"] = 18;
@@ -0,0 +1,23 @@
+json = {};
+json.$y = 4;
+json._x = 3;
+json.a = 2;
+json.a‿b = 15;
+json.b = 1;
+json.classes = 10;
+json.é = 14;
+json.héllo = 12;
+json.x٣ = 18;
+json[""] = 7;
+json["1 2"] = 20;
+json["1"] = 19;
+json["1a"] = 6;
+json["a b"] = 8;
+json["a!"] = 11;
+json["a-b"] = 5;
+json["a.b"] = 21;
+json["a[0]"] = 22;
+json["class"] = 9;
+json["x²"] = 16;
+json["ǅx"] = 13;
+json["٣"] = 17;
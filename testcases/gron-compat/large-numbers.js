@@ -0,0 +1,28 @@
+json = {};
+json.digits = 3.14159265358979323846264338327950288419716939937510582097494459;
+json.floats = [];
+json.floats[0] = 1.7976931348623157e308;
+json.floats[1] = 1e309;
+json.floats[2] = -1e400;
+json.floats[3] = 5e-324;
+json.floats[4] = 1e-400;
+json.floats[5] = 0.1000000000000000055511151231257827;
+json.forms = [];
+json.forms[0] = 0;
+json.forms[1] = -0;
+json.forms[2] = 0.0;
+json.forms[3] = -0.0;
+json.forms[4] = 1E2;
+json.forms[5] = 1e+2;
+json.forms[6] = 1e-2;
+json.forms[7] = 1.50;
+json.forms[8] = 100;
+json.forms[9] = 1.0e0;
+json.i64 = [];
+json.i64[0] = -9223372036854775808;
+json.i64[1] = -9223372036854775809;
+json.i64[2] = -99999999999999999999999999999999999999;
+json.u64 = [];
+json.u64[0] = 18446744073709551615;
+json.u64[1] = 18446744073709551616;
+json.u64[2] = 99999999999999999999999999999999999999;
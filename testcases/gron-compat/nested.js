@@ -0,0 +1,35 @@
+json = [];
+json[0] = {};
+json[0].x = null;
+json[0].y = {};
+json[0].z = [];
+json[1] = [];
+json[1][0] = 0;
+json[1][1] = 1;
+json[1][2] = 2;
+json[1][3] = 3;
+json[1][4] = 4;
+json[1][5] = 5;
+json[1][6] = 6;
+json[1][7] = 7;
+json[1][8] = 8;
+json[1][9] = 9;
+json[1][10] = 10;
+json[1][11] = [];
+json[1][11][0] = true;
+json[1][11][1] = false;
+json[2] = {};
+json[2].k = {};
+json[2].k.b = {};
+json[2].k.b.c = 1;
+json[2].k["b c"] = [];
+json[2].k["b c"][0] = 1;
+json[2].k["b c"][1] = {};
+json[2].k["b c"][1][""] = "e";
+json[2].k["b-c"] = 2;
+json[3] = "top";
+json[4] = -12;
+json[5] = 1.5;
+json[6] = -0.25;
+json[7] = 18446744073709551615;
+json[8] = -9223372036854775808;
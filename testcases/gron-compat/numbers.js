@@ -0,0 +1,10 @@
+json = {};
+json.exponents = [];
+json.exponents[0] = 1E+2;
+json.exponents[1] = 0.1e-2;
+json.exponents[2] = -5e0;
+json.float = 1.0;
+json.huge = 1e400;
+json.id = 123456789012345678901234567890;
+json.price = 1.10;
+json.zero = -0;
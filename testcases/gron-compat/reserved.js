@@ -0,0 +1,49 @@
+json = {};
+json.$if = 43;
+json.Infinity = 46;
+json.NULL = 37;
+json.NaN = 45;
+json.True = 36;
+json._class = 41;
+json.class_ = 42;
+json.classes = 39;
+json.json = 47;
+json.nulls = 38;
+json.undefined = 44;
+json.yields = 40;
+json["break"] = 0;
+json["case"] = 1;
+json["catch"] = 2;
+json["class"] = 3;
+json["const"] = 4;
+json["continue"] = 5;
+json["debugger"] = 6;
+json["default"] = 7;
+json["delete"] = 8;
+json["do"] = 9;
+json["else"] = 10;
+json["export"] = 11;
+json["extends"] = 12;
+json["false"] = 13;
+json["finally"] = 14;
+json["for"] = 15;
+json["function"] = 16;
+json["if"] = 17;
+json["import"] = 18;
+json["in"] = 19;
+json["instanceof"] = 20;
+json["new"] = 21;
+json["null"] = 22;
+json["return"] = 23;
+json["super"] = 24;
+json["switch"] = 25;
+json["this"] = 26;
+json["throw"] = 27;
+json["true"] = 28;
+json["try"] = 29;
+json["typeof"] = 30;
+json["var"] = 31;
+json["void"] = 32;
+json["while"] = 33;
+json["with"] = 34;
+json["yield"] = 35;
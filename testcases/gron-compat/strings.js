@@ -0,0 +1,14 @@
+json = {};
+json.c1 = "\u0085\u009F\u0080\u009F";
+json.controls = "\u0000\u001F\u000B\b\f\n\r\t";
+json.del = "\u007F\u007F";
+json.html = "<a href=\"x\">&amp;</a>";
+json.quotes = "'\"\\";
+json.separators = "\u2028\u2029\u2028\u2029";
+json.slash = "/";
+json.unicode = "Aé😀é😀 ";
+json["a/b"] = "x";
+json["backslash-slash"] = "a\\/b\\";
+json["c1\u0085"] = "z";
+json["line\u2028sep"] = "y";
+json["tab\t"] = "w";
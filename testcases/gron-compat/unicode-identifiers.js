@@ -0,0 +1,25 @@
+json = {};
+json.a‿b = 10;
+json.é = 12;
+json.héllo = 2;
+json.kaः = 14;
+json.x٣ = 8;
+json.ª = 21;
+json.ß = 24;
+json.é = 1;
+json.ʰ = 22;
+json.Ωmega = 19;
+json.ა = 20;
+json.Ⅻ = 7;
+json.ー = 5;
+json.日本 = 6;
+json["a·b"] = 18;
+json["x "] = 23;
+json["x²"] = 15;
+json["xǅ"] = 4;
+json["x‍"] = 16;
+json["ǅx"] = 3;
+json["́e"] = 13;
+json["٣"] = 9;
+json["‿"] = 11;
+json["😀"] = 17;